@@ -0,0 +1,111 @@
+use criterion::{black_box, criterion_group, criterion_main, Criterion};
+use ngrammatic::CorpusBuilder;
+use red_alert::red_alert::{RedAlertVoiceConfig, RedAlertVoiceMatchIndex};
+use std::collections::HashMap;
+
+const ALIASES_COUNT: u64 = 300;
+const TARGET_WORDS: [&str; 2] = ["кикнуть", "выгнать"];
+const SELF_WORDS: [&str; 1] = ["я дурак"];
+const SIMILARITY_THRESHOLD: f32 = 0.75;
+const TEXT: &str = "слушай а давай кикнуть имя42 из этого канала прямо сейчас";
+
+fn aliases() -> Vec<(String, u64)> {
+    (0..ALIASES_COUNT)
+        .map(|alias_index| (format!("имя{}", alias_index), alias_index))
+        .collect()
+}
+
+fn guild_voice_config() -> RedAlertVoiceConfig<u64> {
+    let mut yaml = format!(
+        "target_words: {:?}\n\
+         self_words: {:?}\n\
+         similarity_threshold: {}\n\
+         editors: null\n\
+         aliases:\n",
+        TARGET_WORDS, SELF_WORDS, SIMILARITY_THRESHOLD
+    );
+    for (name, user_id) in aliases() {
+        yaml.push_str(&format!("  \"{}\": {}\n", name, user_id));
+    }
+    serde_yaml::from_str(&yaml).expect("Incorrect benchmark voice config.")
+}
+
+/// Search used before the guild index: a corpus is built for every recognition result and
+/// every configured phrase is looked up in it.
+fn corpus_should_kick<'a>(
+    aliases: &'a [(String, u64)],
+    author_user_id: &'a u64,
+    text: &str,
+) -> HashMap<&'a u64, (String, f32)> {
+    let mut corpus = CorpusBuilder::new().finish();
+    for text_word in text.split_ascii_whitespace() {
+        corpus.add_text(text_word);
+    }
+    let check_text_contains = |query: &str| -> Option<(String, f32)> {
+        let query_words: Vec<&str> = query.split_ascii_whitespace().collect();
+        let single_word_threshold = SIMILARITY_THRESHOLD / (query_words.len() as f32);
+        let mut real_query_words: Vec<String> = vec![];
+        let mut total_similarity_sum: f32 = 0.0;
+        for query_word in query_words {
+            let mut search_result = corpus.search(query_word, single_word_threshold);
+            if search_result.is_empty() {
+                return None;
+            }
+            let first_search_result = search_result.remove(0);
+            let real_letters_count = first_search_result.text.len();
+            let letters_count_threshold =
+                ((real_letters_count as f32) * (1.0 - SIMILARITY_THRESHOLD)).round() as usize;
+            let letters_count = query_word.len();
+            if real_letters_count.max(letters_count) - real_letters_count.min(letters_count)
+                > letters_count_threshold
+            {
+                return None;
+            }
+            real_query_words.push(first_search_result.text);
+            total_similarity_sum += first_search_result.similarity;
+        }
+        let total_similarity = total_similarity_sum / (real_query_words.len() as f32);
+        let real_query = real_query_words.join(" ");
+        if total_similarity >= SIMILARITY_THRESHOLD && text.contains(&real_query) {
+            Some((real_query, total_similarity))
+        } else {
+            None
+        }
+    };
+    let mut users_ids = HashMap::new();
+    for self_word in SELF_WORDS {
+        if let Some(search_result) = check_text_contains(self_word) {
+            users_ids.insert(author_user_id, search_result);
+            break;
+        }
+    }
+    for target_word in TARGET_WORDS {
+        if check_text_contains(target_word).is_none() {
+            continue;
+        }
+        for (name, user_id) in aliases {
+            let target_word_name = format!("{} {}", target_word, name);
+            if let Some(search_result) = check_text_contains(&target_word_name) {
+                users_ids.insert(user_id, search_result);
+            }
+        }
+    }
+    users_ids
+}
+
+fn voice_match_index(c: &mut Criterion) {
+    let config = guild_voice_config();
+    let aliases = aliases();
+    let author_id: u64 = 0;
+    let text = TEXT.to_owned();
+    let match_index = RedAlertVoiceMatchIndex::new(&config, None);
+    c.bench_function("indexed", |b| {
+        b.iter(|| black_box(match_index.should_kick(&author_id, black_box(&text)).len()))
+    });
+    c.bench_function("corpus_per_result", |b| {
+        b.iter(|| black_box(corpus_should_kick(&aliases, &author_id, black_box(&text)).len()))
+    });
+}
+
+criterion_group!(benches, voice_match_index);
+criterion_main!(benches);
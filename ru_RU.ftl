@@ -7,7 +7,7 @@ red-alert-command-prefix-anchor = код красный
 red-alert-command-header-suffix = {"{"}ID или упоминание пользователя{"}"}*
 red-alert-command-help-description =
     {"*"} - может быть несколько (через пробел).
-    Кикает выбранного пользователя из голосового канала если он в нем находится, иначе, кикает исполнителя команды.
+    Наказывает (по умолчанию кикает, смотри наказание в настройках голоса) выбранного пользователя из голосового канала если он в нем находится, иначе, наказывает исполнителя команды.
 red-alert-command-empty-self-success = ВИЖУ ТЫ ЗАБЫЛ УКАЗАТЬ ЦЕЛЬ ДЛЯ КРАСНОГО КОДА, НИЧЕГО... ШМАЛЬНЕМ В ТЕБЯ! (ИСПОЛЬЗУЙ ТЕГИ) ПРИНЯТО К ИСПОЛНЕНИЮ!
 red-alert-command-empty-self-not-found = :face_with_monocle: ПОЛЬЗУЙСЯ ТЕГАМИ, И ЛУЧШЕ НЕ ЗАХОДИ В КАНАЛ, А ТО КИКНУ С ТАКИМИ ПРИКОЛАМИ! Пшшшш...
 red-alert-command-empty-self-error = СЛОМАЛСЯ ПОКА ПЫТАЛСЯ ТЕБЯ КИКНУТЬ ЧТО НЕПРАВИЛЬНОЕ ИСПОЛЬЗОВАНИЕ, КАК ВСЕГДА КОД ГОВНА! ОТМЕНА! Пшшшш...
@@ -17,6 +17,12 @@ red-alert-command-single-not-found-self-success = В КАНАЛЕ НЕТ ЧЕЛ
 red-alert-command-single-not-found-self-not-found = ДОФИГА УМНЫЙ ВИЖУ? В КАНАЛЕ НЕТ ЧЕЛА ДЛЯ КОДА КРАСНОГО, ЖАЛЬ ТЕБЯ В КАНАЛЕ НЕТУ, ТАК БЫ ТЕБЯ ШМАЛЬНУЛ КОДОМ КРАСНЫМ! ОТМЕНА! Пшшшш...
 red-alert-command-single-not-found-self-error = ХОТЕЛ ШМАЛЬНУТЬ В ТЕБЯ ЗА ТО ЧТО ТЫ ПЫТАЛСЯ КИКНУТЬ ТОГО КОГО НЕТ, НО Я СЛОМАЛСЯ! Пшшшш...
 red-alert-command-single-not-found-self = СУИЦИД ЭТО ПЛОХО ТАК ЧТО НЕТ))) (У меня просто не получилось)
+red-alert-command-empty-self-immune = ЗАБЫЛ УКАЗАТЬ ЦЕЛЬ, НО ТЕБЯ ТРОГАТЬ НЕЛЬЗЯ, ТАК ЧТО ПРОЕХАЛИ!
+red-alert-command-single-target-immune = {$user-name} НЕПРИКАСАЕМЫЙ! КОД КРАСНЫЙ ОТМЕНЕН!
+red-alert-command-single-self-immune = ТЫ НЕПРИКАСАЕМЫЙ, САМОВЫПИЛ НЕ ПРОЙДЕТ!
+red-alert-command-single-not-found-self-immune = В КАНАЛЕ НЕТ ЧЕЛА ДЛЯ КОДА КРАСНОГО, А ТЕБЯ ТРОГАТЬ НЕЛЬЗЯ! ОТМЕНА! Пшшшш...
+red-alert-command-mass-self-immune = МАССОВЫЙ КОД КРАСНЫЙ НИКОГО НЕ ЗАДЕЛ, А ТЕБЯ ТРОГАТЬ НЕЛЬЗЯ! ОТМЕНА! Пшшшш...
+red-alert-command-mass-immune-status = НЕПРИКАСАЕМЫЙ
 red-alert-command-single-target-error = АУЧ, МАСЛИНУ ПОЙМАЛ, ОШИБКА В СИСТЕМЕё0))
 red-alert-command-single-self-error = АУЧ, МАСЛИНУ ПОЙМАЛ, НЕ СМОГ ОРГАНИЗОВАТЬ ТЕБЕ СУИЦИД0))
 red-alert-command-mass-self-success = МАССОВЫЙ КОД КРАСНЫЙ ШТУКА ОПАСНАЯ, ТАК КАК ПО РАЗНЫМ ПРИЧИНАМ Я НИКОГО НЕ КИКНУЛ, КИКНУ ТЕБЯ )В)В)))0
@@ -26,6 +32,7 @@ red-alert-command-mass-success-status = ИСПОЛНЕНО
 red-alert-command-mass-not-found-status = НЕ В КАНАЛЕ
 red-alert-command-mass-error-status = ОШИБКА (ПРОЧНЫЙ СУ*А)
 red-alert-command-mass-record = {$record-number}. {$user-name} СТАТУС: {$deport-status}.
+red-alert-command-rate-limited-record = {$user-name} НЕ ТРОНУТ: {$limit}
 red-alert-command-mass-records-header = ОУ, МАССОВЫЙ КОД КРАСНЫЙ? СТАТУС ВЫКОСА КРИНЖОВИКОВ:
 start-listen-red-alert-command-prefix-anchor = слушать код красный
 start-listen-red-alert-command-header-suffix = {"{"}ID или упоминание канала{"}"}
@@ -33,6 +40,7 @@ start-listen-red-alert-command-help-description = Начать слушать в
 start-listen-red-alert-command-success = ОТСЛЕЖИВАЮ КОД КРАСНЫЙ В КАНАЛЕ {$channel-name}...
 start-listen-red-alert-command-connect-error = ОШИБКА СЛЕЖКИ ЗА КАНАЛОМ {$channel-name}. НЕ ПОЛУЧАЕТСЯ ВОЙТИ В КАНАЛ...
 start-listen-red-alert-command-lib-error = ОШИБКА СЛЕЖКИ ЗА КАНАЛОМ {$channel-name}. ЗВУКОВАЯ БИБЛИОТЕКА ОТСУТСТВУЕТ...
+start-listen-red-alert-command-preflight-error = ОШИБКА СЛЕЖКИ ЗА КАНАЛОМ {$channel-name}. {$reason}
 start-listen-red-alert-command-missed-channel = ЧТО ОТСЛЕЖИВАТЬ НАРКОМАН?
 stop-listen-red-alert-command-prefix-anchor = прекратить слушать код красный
 stop-listen-red-alert-command-help-description = Прекратить слушать голосовой канал в котором находится КРИНЖ КИЛЛЕР на запрещенные и направленные фразы.
@@ -49,19 +57,48 @@ actions-history-red-alert-command-target-kick-status-success = КИКНУТ
 actions-history-red-alert-command-target-kick-status-fail = ПОЧТИ... КИКНУТ
 actions-history-red-alert-command-voice-record-time-format = %d/%m/%Y %H:%M
 actions-history-red-alert-command-voice-record-reason-format = __{$reason}__
-actions-history-red-alert-command-voice-self-record = КРИНЖОВИК {$target-name} {$status} ФРАЗОЙ "{$reason-text}" ГДЕ ЕСТЬ СОВПАДЕНИЕ С "{$restricted-word}" НА {$similarity-percent}%.
-actions-history-red-alert-command-voice-target-record = КРИНЖОВИК {$target-name} {$status} ГОЛОСОМ МИРОТВОРЦA {$author-name} ПРИ ПОМОЩИ ФРАЗЫ "{$reason-text}" ГДЕ ЕСТЬ СОВПАДЕНИЕ С "{$restricted-word}" НА {$similarity-percent}%.
-actions-history-red-alert-command-text-self-record = КРИНЖОВИК {$target-name} {$status} КОМАНДОЙ
-actions-history-red-alert-command-text-target-record = КРИНЖОВИК {$target-name} {$status} КОМАНДОЙ МИРОТВОРЦA {$author-name}
+actions-history-red-alert-command-voice-self-record = КРИНЖОВИК {$target-name} {$status} ({$action}) ФРАЗОЙ "{$reason-text}" ГДЕ ЕСТЬ СОВПАДЕНИЕ С "{$restricted-word}" НА {$similarity-percent}%.
+actions-history-red-alert-command-voice-target-record = КРИНЖОВИК {$target-name} {$status} ({$action}) ГОЛОСОМ МИРОТВОРЦA {$author-name} ПРИ ПОМОЩИ ФРАЗЫ "{$reason-text}" ГДЕ ЕСТЬ СОВПАДЕНИЕ С "{$restricted-word}" НА {$similarity-percent}%.
+actions-history-red-alert-command-voice-suppressed-record = КРИНЖОВИК {$target-name} ИЗБЕЖАЛ КИКА ОТ МИРОТВОРЦA {$author-name} ПРИ ПОМОЩИ ФРАЗЫ "{$reason-text}" С "{$restricted-word}", ТАК КАК СРАБОТАЛА РАЗРЕШЕННАЯ ФРАЗА "{$exception}"
+actions-history-red-alert-command-vote-record = ГОЛОСОВАНИЕ ЗА НАКАЗАНИЕ КРИНЖОВИКА {$target-name} ОТ МИРОТВОРЦA {$author-name} ПО ФРАЗЕ "{$reason-text}": {$outcome}
+actions-history-red-alert-command-cancelled-record = НАКАЗАНИЕ КРИНЖОВИКА {$target-name} ОТ МИРОТВОРЦA {$author-name} ОТМЕНЕНО: {$reason}
+actions-history-red-alert-command-failed-attempt-retry-record = НАКАЗАНИЕ КРИНЖОВИКА {$target-name} ({$action}) НЕ УДАЛОСЬ С ПОПЫТКИ {$attempt}/{$max-attempts}, ПОВТОРЯЕМ: {$error}
+actions-history-red-alert-command-failed-attempt-record = НАКАЗАНИЕ КРИНЖОВИКА {$target-name} ({$action}) ПРОВАЛЕНО НА ПОПЫТКЕ {$attempt}/{$max-attempts}: {$error}
+actions-history-red-alert-command-immune-record = КРИНЖОВИК {$target-name} НЕПРИКАСАЕМЫЙ, МИРОТВОРЕЦ {$author-name} ЗРЯ СТАРАЛСЯ
+actions-history-red-alert-command-rate-limited-record = КРИНЖОВИК {$target-name} ИЗБЕЖАЛ НАКАЗАНИЯ ОТ МИРОТВОРЦA {$author-name}, ТАК КАК СРАБОТАЛО ОГРАНИЧЕНИЕ: {$limit}
+actions-history-red-alert-command-text-self-record = КРИНЖОВИК {$target-name} {$status} ({$action}) КОМАНДОЙ
+actions-history-red-alert-command-text-target-record = КРИНЖОВИК {$target-name} {$status} ({$action}) КОМАНДОЙ МИРОТВОРЦA {$author-name}
 actions-history-red-alert-command-record = {$record-number}. [ВРЕМЯ: {$time}] {$record}.
 actions-history-red-alert-command-empty-list = ПОКА ЕЩЕ НИКОГО НЕ УШАТАЛ НА ЭТОМ СЕРВЕР)!1!))
 guilds-voice-config-red-alert-command-prefix-anchor = код красный настройка голоса
-guilds-voice-config-red-alert-command-header-suffix = [запретная/выгоняющая/псевдоним/погрешность/список/автослежение]
+guilds-voice-config-red-alert-command-header-suffix = [запретная/выгоняющая/разрешенная/псевдоним/иммунитет/погрешность/алгоритм/фонетика/промах/наказание/эскалация/кулдаун/голосование/отсчет/отмена/лог/сирена/громкость/повтор/уведомление/окно/язык/стемминг/автопсевдонимы/список/автослежение]
 guilds-voice-config-red-alert-command-help-description =
     {"["}запретная{"]"} {"{"}фраза{"}"} - добавляет/удаляет фразу при призношении которой пользователь будет исключен.
     {"["}выгоняющая{"]"} {"{"}фраза{"}"} - добавляет/удаляет фразу при призношении которой пользователь может исключить другого пользователя.
-    {"["}псевдоним{"]"} {"{"}фраза{"}"} {"{"}ID или упоминание пользователя{"}"} - добавляет/удаляет псевдоним для пользователя который можно использовать в распознавателе речи.
+    Запретная и выгоняющая фраза может быть шаблоном: "красн* тревог*" (* - любые буквы, ? - одна буква) или регулярным выражением "/красн\w+ тревог\w+/".
+    {"["}разрешенная{"]"} {"{"}фраза{"}"} - добавляет/удаляет фразу которая отменяет исключение пользователя если пересекается с найденной фразой (например "не говори красная тревога").
+    {"["}псевдоним{"]"} {"{"}фраза{"}"} {"{"}ID или упоминание пользователя или роли{"}"} - добавляет/удаляет пользователя или роль в псевдоним который можно использовать в распознавателе речи (псевдоним может указывать на нескольких пользователей и роли, роль выгоняет всех ее участников в голосовом канале).
+    {"["}иммунитет{"]"} {"{"}ID или упоминание пользователя или роли{"}"} - добавляет/удаляет пользователя или роль которых нельзя наказать ни голосом, ни командой.
     {"["}погрешность{"]"} {"{"}0.0 - 1.0{"}"} - устанавливает погрешность разпознавания речи.
+    {"["}алгоритм{"]"} {"{"}ngram/levenshtein/jaro_winkler/exact_token/phonetic{"}"} - устанавливает алгоритм сопоставления фраз.
+    {"["}фонетика{"]"} {"{"}0.0 - 1.0{"}"} - устанавливает долю фонетического сходства в оценке (учитывает похожее звучание и латиницу).
+    {"["}промах{"]"} {"{"}0.0 - 1.0{"}"} - запоминает фразы которые не дотянули до погрешности не больше чем на это значение (0 - выключено), смотри "код красный промахи".
+    {"["}наказание{"]"} {"{"}warn/disconnect/mute/deafen/move канал/timeout минуты/temporary_role роль минуты{"}"} - устанавливает наказание: предупреждение в личные сообщения, исключение из канала, отключение микрофона, отключение звука, перенос в канал, тайм-аут или временная роль.
+    {"["}эскалация{"]"} {"{"}окно в минутах{"}"} {"{"}наказание{"}"}+ - устанавливает лестницу наказаний для повторных нарушений (например "эскалация 60 warn move канал disconnect timeout 10"), нарушения старше окна забываются (0 - никогда), значение "-" выключает, смотри "код красный нарушения".
+    {"["}кулдаун{"]"} {"{"}цель/автор/сервер{"}"} {"{"}число{"}"} - устанавливает сколько секунд нельзя наказывать того же пользователя (цель), сколько секунд пользователь не может снова наказывать других (автор) или сколько наказаний в минуту разрешено на сервере (сервер), 0 - выключено.
+    {"["}голосование{"]"} {"{"}ID или упоминание текстового канала{"}"} {"{"}число голосов{"}"} {"{"}секунды{"}"} - голосовое наказание исполняется только если указанное число участников того же голосового канала подтвердит его реакцией в текстовом канале за отведенное время, значение "-" выключает.
+    {"["}отсчет{"]"} {"{"}секунды{"}"} - голосовое наказание исполняется только после отсчета, который объявляется в канале логов с кнопкой отмены для редакторов (0 - выключено), смотри "код красный отсчет".
+    {"["}отмена{"]"} {"{"}фраза{"}"} - добавляет/удаляет фразу, сказав которую наказуемый отменяет свое наказание во время отсчета.
+    {"["}лог{"]"} {"{"}ID или упоминание текстового канала{"}"} - устанавливает канал логов, значение "-" выключает.
+    {"["}сирена{"]"} {"{"}путь к аудиофайлу{"}"} - проигрывает сирену в голосовом канале при наказании или начале отсчета, значение "-" выключает.
+    {"["}громкость{"]"} {"{"}0.0 - 1.0{"}"} - устанавливает громкость сирены.
+    {"["}повтор{"]"} {"{"}попытки{"}"} {"{"}задержка мс{"}"} - повторяет наказание при сбоях Discord с растущей задержкой, 1 попытка выключает повторы.
+    {"["}уведомление{"]"} {"{"}текст апелляции{"}"} - без параметров включает/выключает личное сообщение наказанному (или сообщение в канал логов, если личные сообщения закрыты), с текстом задает способ обжалования, значение "-" сбрасывает его.
+    Погрешность, алгоритм, фонетику, наказание и сирену можно задать отдельной фразе или псевдониму, указав ее после значения (значение "-" сбрасывает настройку фразы).
+    {"["}окно{"]"} {"{"}число слов{"}"} - выгоняющая фраза и псевдоним могут стоять в любом порядке в пределах указанного числа слов (0 - только подряд).
+    {"["}язык{"]"} {"{"}ru/en{"}"} - устанавливает язык нормализации текста (ё, числа, знаки препинания).
+    {"["}стемминг{"]"} - включает/выключает отбрасывание окончаний слов при сравнении.
+    {"["}автопсевдонимы{"]"} - включает/выключает псевдонимы из ников и имен участников (с транслитерацией и уменьшительными формами), ручные псевдонимы важнее.
     {"["}список{"]"} - список всех фраз.
     {"["}автослежение{"]"} - включает/выключает автослежение за голосовыми каналами (подключается к каналам где находится больше всего людей).
 guilds-voice-config-red-alert-command-no-access = АТДЫХАЙ, У ТЕБЯ НЕТУ ДОСТУПА!
@@ -69,8 +106,31 @@ guilds-voice-config-red-alert-command-empty-action = НЕ УКАЗАНО ДЕЙ
 guilds-voice-config-red-alert-command-incorrect-action = НЕТУ ТАКОГО ДЕЙСТВИЯ!
 guilds-voice-config-red-alert-command-self-words-action = запретная
 guilds-voice-config-red-alert-command-target-words-action = выгоняющая
+guilds-voice-config-red-alert-command-exceptions-action = разрешенная
 guilds-voice-config-red-alert-command-aliases-action = псевдоним
+guilds-voice-config-red-alert-command-immunity-action = иммунитет
 guilds-voice-config-red-alert-command-similarity-threshold-action = погрешность
+guilds-voice-config-red-alert-command-matcher-action = алгоритм
+guilds-voice-config-red-alert-command-phonetic-weight-action = фонетика
+guilds-voice-config-red-alert-command-near-miss-margin-action = промах
+guilds-voice-config-red-alert-command-punishment-action = наказание
+guilds-voice-config-red-alert-command-escalation-action = эскалация
+guilds-voice-config-red-alert-command-cooldown-action = кулдаун
+guilds-voice-config-red-alert-command-cooldown-target-kind = цель
+guilds-voice-config-red-alert-command-cooldown-author-kind = автор
+guilds-voice-config-red-alert-command-cooldown-guild-kind = сервер
+guilds-voice-config-red-alert-command-vote-action = голосование
+guilds-voice-config-red-alert-command-countdown-action = отсчет
+guilds-voice-config-red-alert-command-cancel-phrases-action = отмена
+guilds-voice-config-red-alert-command-log-channel-action = лог
+guilds-voice-config-red-alert-command-siren-action = сирена
+guilds-voice-config-red-alert-command-siren-volume-action = громкость
+guilds-voice-config-red-alert-command-retry-action = повтор
+guilds-voice-config-red-alert-command-notification-action = уведомление
+guilds-voice-config-red-alert-command-proximity-window-action = окно
+guilds-voice-config-red-alert-command-language-action = язык
+guilds-voice-config-red-alert-command-stemming-action = стемминг
+guilds-voice-config-red-alert-command-derive-aliases-action = автопсевдонимы
 guilds-voice-config-red-alert-command-editors-action = редактор
 guilds-voice-config-red-alert-command-list-action = список
 guilds-voice-config-red-alert-command-auto-track-action = автослежение
@@ -78,27 +138,218 @@ guilds-voice-config-red-alert-command-self-words-add = ЗАПРЕТНАЯ ФРА
 guilds-voice-config-red-alert-command-self-words-remove = ЗАПРЕТНАЯ ФРАЗА УДАЛЕНА!
 guilds-voice-config-red-alert-command-target-words-add = ВЫГОНЯЮЩАЯ ФРАЗА ДОБАВЛЕНА!
 guilds-voice-config-red-alert-command-target-words-remove = ВЫГОНЯЮЩАЯ ФРАЗА УДАЛЕНА!
+guilds-voice-config-red-alert-command-pattern-error = НЕПРАВИЛЬНЫЙ ШАБЛОН ФРАЗЫ! {$error}
+guilds-voice-config-red-alert-command-exceptions-add = РАЗРЕШЕННАЯ ФРАЗА ДОБАВЛЕНА!
+guilds-voice-config-red-alert-command-exceptions-remove = РАЗРЕШЕННАЯ ФРАЗА УДАЛЕНА!
 guilds-voice-config-red-alert-command-aliases-empty-params = МАЛО ПАРАМЕТРОВ!
-guilds-voice-config-red-alert-command-aliases-incorrect-user = НЕВЕРНЫЙ ПОЛЬЗОВАТЕЛЬ!
+guilds-voice-config-red-alert-command-aliases-incorrect-user = НЕВЕРНЫЙ ПОЛЬЗОВАТЕЛЬ ИЛИ РОЛЬ!
 guilds-voice-config-red-alert-command-aliases-add = ДОБАВЛЕН ПСЕВДОНИМ ДЛЯ {$user-name}!
 guilds-voice-config-red-alert-command-aliases-remove = УДАЛЕН ПСЕВДОНИМ ДЛЯ {$user-name}!
-guilds-voice-config-red-alert-command-aliases-replace = ЗАМЕНЕН ПСЕВДОНИМ ДЛЯ {$user-name}!
+guilds-voice-config-red-alert-command-immunity-empty-params = МАЛО ПАРАМЕТРОВ!
+guilds-voice-config-red-alert-command-immunity-incorrect-user = НЕВЕРНЫЙ ПОЛЬЗОВАТЕЛЬ ИЛИ РОЛЬ!
+guilds-voice-config-red-alert-command-immunity-add = {$user-name} ТЕПЕРЬ НЕПРИКАСАЕМЫЙ!
+guilds-voice-config-red-alert-command-immunity-remove = {$user-name} БОЛЬШЕ НЕ НЕПРИКАСАЕМЫЙ!
 guilds-voice-config-red-alert-command-similarity-threshold-empty-params = НЕ УКАЗАНА ПОГРЕШНОСТЬ!
 guilds-voice-config-red-alert-command-similarity-threshold-incorrect-params = НЕПРАВИЛЬНЫЙ ФОРМАТ ПОГРЕШНОСТИ!
 guilds-voice-config-red-alert-command-similarity-threshold-success = ПОГРЕШНОСТЬ ОБНОВЛЕНА НА ЗНАЧЕНИЕ: {$similarity-threshold}!
+guilds-voice-config-red-alert-command-matcher-empty-params = НЕ УКАЗАН АЛГОРИТМ!
+guilds-voice-config-red-alert-command-matcher-incorrect-params = НЕТУ ТАКОГО АЛГОРИТМА! ДОСТУПНЫ: {$matchers}
+guilds-voice-config-red-alert-command-matcher-success = АЛГОРИТМ СОПОСТАВЛЕНИЯ ОБНОВЛЕН НА: {$matcher}!
+guilds-voice-config-red-alert-command-phonetic-weight-empty-params = НЕ УКАЗАНА ДОЛЯ ФОНЕТИКИ!
+guilds-voice-config-red-alert-command-phonetic-weight-incorrect-params = НЕПРАВИЛЬНЫЙ ФОРМАТ ДОЛИ ФОНЕТИКИ!
+guilds-voice-config-red-alert-command-phonetic-weight-success = ДОЛЯ ФОНЕТИКИ ОБНОВЛЕНА НА ЗНАЧЕНИЕ: {$phonetic-weight}!
+guilds-voice-config-red-alert-command-near-miss-margin-empty-params = НЕ УКАЗАН ЗАПАС ПРОМАХА!
+guilds-voice-config-red-alert-command-near-miss-margin-incorrect-params = НЕПРАВИЛЬНЫЙ ФОРМАТ ЗАПАСА ПРОМАХА!
+guilds-voice-config-red-alert-command-near-miss-margin-success = ЗАПАС ПРОМАХА ОБНОВЛЕН НА ЗНАЧЕНИЕ: {$near-miss-margin}!
+guilds-voice-config-red-alert-command-punishment-empty-params = НЕ УКАЗАНО НАКАЗАНИЕ!
+guilds-voice-config-red-alert-command-punishment-incorrect-params = НЕПРАВИЛЬНОЕ НАКАЗАНИЕ! ДОСТУПНЫ: {$actions}
+guilds-voice-config-red-alert-command-punishment-success = НАКАЗАНИЕ ОБНОВЛЕНО НА: {$action}!
+guilds-voice-config-red-alert-command-escalation-empty-params = НЕ УКАЗАНЫ ОКНО И СТУПЕНИ ЭСКАЛАЦИИ!
+guilds-voice-config-red-alert-command-escalation-incorrect-params = НЕПРАВИЛЬНАЯ ЭСКАЛАЦИЯ! ДОСТУПНЫ НАКАЗАНИЯ: {$actions}
+guilds-voice-config-red-alert-command-escalation-success = ЭСКАЛАЦИЯ ОБНОВЛЕНА: {$steps}!
+guilds-voice-config-red-alert-command-escalation-disabled = ЭСКАЛАЦИЯ __ВЫКЛЮЧЕНА__!
+guilds-voice-config-red-alert-command-cooldown-empty-params = МАЛО ПАРАМЕТРОВ!
+guilds-voice-config-red-alert-command-cooldown-incorrect-params = НЕПРАВИЛЬНЫЙ КУЛДАУН! НУЖНО: цель/автор/сервер И ЧИСЛО
+guilds-voice-config-red-alert-command-cooldown-target-success = ОДНОГО И ТОГО ЖЕ ПОЛЬЗОВАТЕЛЯ МОЖНО НАКАЗАТЬ РАЗ В {$value} СЕК.!
+guilds-voice-config-red-alert-command-cooldown-author-success = ПОЛЬЗОВАТЕЛЬ МОЖЕТ НАКАЗЫВАТЬ ДРУГИХ РАЗ В {$value} СЕК.!
+guilds-voice-config-red-alert-command-cooldown-guild-success = НА СЕРВЕРЕ РАЗРЕШЕНО {$value} НАКАЗАНИЙ В МИНУТУ!
+guilds-voice-config-red-alert-command-vote-empty-params = МАЛО ПАРАМЕТРОВ!
+guilds-voice-config-red-alert-command-vote-incorrect-params = НЕПРАВИЛЬНОЕ ГОЛОСОВАНИЕ! НУЖНО: КАНАЛ, ЧИСЛО ГОЛОСОВ И СЕКУНДЫ
+guilds-voice-config-red-alert-command-vote-success = ГОЛОСОВАНИЕ ВКЛЮЧЕНО: {$vote}!
+guilds-voice-config-red-alert-command-vote-disabled = ГОЛОСОВАНИЕ __ВЫКЛЮЧЕНО__!
+guilds-voice-config-red-alert-command-vote-description = {$channel-name}, {$votes} голос(ов) за {$seconds} сек.
+guilds-voice-config-red-alert-command-countdown-empty-params = НЕ УКАЗАНЫ СЕКУНДЫ ОТСЧЕТА!
+guilds-voice-config-red-alert-command-countdown-incorrect-params = НЕПРАВИЛЬНЫЙ ФОРМАТ СЕКУНД ОТСЧЕТА! НЕ БОЛЬШЕ {$max-seconds} СЕК.
+guilds-voice-config-red-alert-command-countdown-success = ГОЛОСОВОЕ НАКАЗАНИЕ ИСПОЛНЯЕТСЯ ЧЕРЕЗ {$seconds} СЕК.!
+guilds-voice-config-red-alert-command-countdown-disabled = ОТСЧЕТ __ВЫКЛЮЧЕН__!
+guilds-voice-config-red-alert-command-cancel-phrases-empty-params = НЕ УКАЗАНА ФРАЗА ОТМЕНЫ!
+guilds-voice-config-red-alert-command-cancel-phrases-add = ФРАЗА ОТМЕНЫ ДОБАВЛЕНА!
+guilds-voice-config-red-alert-command-cancel-phrases-remove = ФРАЗА ОТМЕНЫ УДАЛЕНА!
+guilds-voice-config-red-alert-command-log-channel-empty-params = НЕ УКАЗАН КАНАЛ ЛОГОВ!
+guilds-voice-config-red-alert-command-log-channel-incorrect-params = НЕВЕРНЫЙ КАНАЛ ЛОГОВ!
+guilds-voice-config-red-alert-command-log-channel-success = КАНАЛ ЛОГОВ ОБНОВЛЕН НА: {$channel-name}!
+guilds-voice-config-red-alert-command-log-channel-disabled = КАНАЛ ЛОГОВ __ВЫКЛЮЧЕН__!
+guilds-voice-config-red-alert-command-siren-empty-params = НЕ УКАЗАН ФАЙЛ СИРЕНЫ!
+guilds-voice-config-red-alert-command-siren-not-configured = ПАПКА СИРЕН НЕ НАСТРОЕНА В CONFIG.YAML!
+guilds-voice-config-red-alert-command-siren-forbidden-path = СИРЕНА ДОЛЖНА БЫТЬ ИМЕНЕМ ФАЙЛА ИЗ ПАПКИ СИРЕН: {$path}!
+guilds-voice-config-red-alert-command-siren-not-found = ФАЙЛ СИРЕНЫ НЕ НАЙДЕН: {$path}!
+guilds-voice-config-red-alert-command-siren-success = СИРЕНА ОБНОВЛЕНА НА: {$path}!
+guilds-voice-config-red-alert-command-siren-disabled = СИРЕНА __ВЫКЛЮЧЕНА__!
+guilds-voice-config-red-alert-command-siren-volume-empty-params = НЕ УКАЗАНА ГРОМКОСТЬ!
+guilds-voice-config-red-alert-command-siren-volume-incorrect-params = НЕПРАВИЛЬНЫЙ ФОРМАТ ГРОМКОСТИ!
+guilds-voice-config-red-alert-command-siren-volume-success = ГРОМКОСТЬ СИРЕНЫ ОБНОВЛЕНА НА ЗНАЧЕНИЕ: {$volume}!
+guilds-voice-config-red-alert-command-retry-empty-params = НЕ УКАЗАНО КОЛИЧЕСТВО ПОПЫТОК!
+guilds-voice-config-red-alert-command-retry-incorrect-params = НЕПРАВИЛЬНЫЙ ФОРМАТ ПОВТОРОВ!
+guilds-voice-config-red-alert-command-retry-success = НАКАЗАНИЕ ПОВТОРЯЕТСЯ ДО {$attempts} РАЗ С НАЧАЛЬНОЙ ЗАДЕРЖКОЙ {$delay} МС!
+guilds-voice-config-red-alert-command-notification-add = УВЕДОМЛЕНИЕ НАКАЗАННЫХ __ВКЛЮЧЕНО__!
+guilds-voice-config-red-alert-command-notification-remove = УВЕДОМЛЕНИЕ НАКАЗАННЫХ __ВЫКЛЮЧЕНО__!
+guilds-voice-config-red-alert-command-notification-appeal-success = СПОСОБ ОБЖАЛОВАНИЯ ОБНОВЛЕН НА: {$appeal}
+guilds-voice-config-red-alert-command-notification-appeal-reset = СПОСОБ ОБЖАЛОВАНИЯ СБРОШЕН!
+guilds-voice-config-red-alert-command-proximity-window-empty-params = НЕ УКАЗАН РАЗМЕР ОКНА!
+guilds-voice-config-red-alert-command-proximity-window-incorrect-params = НЕПРАВИЛЬНЫЙ ФОРМАТ РАЗМЕРА ОКНА!
+guilds-voice-config-red-alert-command-proximity-window-success = ВЫГОНЯЮЩАЯ ФРАЗА И ПСЕВДОНИМ ИЩУТСЯ В ОКНЕ ИЗ {$proximity-window} СЛОВ!
+guilds-voice-config-red-alert-command-proximity-window-disabled = ВЫГОНЯЮЩАЯ ФРАЗА И ПСЕВДОНИМ ИЩУТСЯ ТОЛЬКО ПОДРЯД!
+guilds-voice-config-red-alert-command-language-empty-params = НЕ УКАЗАН ЯЗЫК!
+guilds-voice-config-red-alert-command-language-incorrect-params = НЕТУ ТАКОГО ЯЗЫКА! ДОСТУПНЫ: {$languages}
+guilds-voice-config-red-alert-command-language-success = ЯЗЫК НОРМАЛИЗАЦИИ ОБНОВЛЕН НА: {$language}!
+guilds-voice-config-red-alert-command-stemming-add = СТЕММИНГ __ВКЛЮЧЕН__!
+guilds-voice-config-red-alert-command-stemming-remove = СТЕММИНГ __ВЫКЛЮЧЕН__!
+guilds-voice-config-red-alert-command-derive-aliases-add = АВТОМАТИЧЕСКИЕ ПСЕВДОНИМЫ __ВКЛЮЧЕНЫ__!
+guilds-voice-config-red-alert-command-derive-aliases-remove = АВТОМАТИЧЕСКИЕ ПСЕВДОНИМЫ __ВЫКЛЮЧЕНЫ__!
+guilds-voice-config-red-alert-command-entry-not-found = НЕТУ ТАКОЙ ФРАЗЫ ИЛИ ПСЕВДОНИМА!
+guilds-voice-config-red-alert-command-entry-options-success = НАСТРОЙКИ ДЛЯ "{$entry}" ОБНОВЛЕНЫ!
 guilds-voice-config-red-alert-command-editors-empty-params = МАЛО ПАРАМЕТРОВ!
 guilds-voice-config-red-alert-command-editors-incorrect-user = НЕВЕРНЫЙ ПОЛЬЗОВАТЕЛЬ!
 guilds-voice-config-red-alert-command-editors-add = РЕДАКТОР ДОБАВЛЕН!
 guilds-voice-config-red-alert-command-editors-remove = РЕДАКТОР УДАЛЕН!
 guilds-voice-config-red-alert-command-editors-one-error = НЕВОЗМОЖНО УДАЛИТЬ ПОСЛЕДНЕГО РЕДАКТОРА! ВСЕГДА ДОЛЖЕН БЫТЬ КОРОЛЬ ЛИЧ!
 guilds-voice-config-red-alert-command-list-template = 
+    {"*"}{"*"}Наказание:{"*"}{"*"} {$punishment}
+    {"*"}{"*"}Эскалация:{"*"}{"*"} {$escalation}
+    {"*"}{"*"}Иммунитет:{"*"}{"*"} {$immunity}
+    {"*"}{"*"}Голосование:{"*"}{"*"} {$vote}
+    {"*"}{"*"}Отсчет:{"*"}{"*"} {$countdown} сек., фразы отмены: {$cancel-phrases}
+    {"*"}{"*"}Канал логов:{"*"}{"*"} {$log-channel}
+    {"*"}{"*"}Сирена:{"*"}{"*"} {$siren}, громкость {$siren-volume}
+    {"*"}{"*"}Повторы:{"*"}{"*"} {$retry-attempts} попыток, задержка {$retry-delay} мс
+    {"*"}{"*"}Уведомление:{"*"}{"*"} { $notification ->
+        [true] включено
+       *[other] выключено
+    }, обжалование: {$notification-appeal}
+    {"*"}{"*"}Кулдауны:{"*"}{"*"} цель {$cooldown-target} сек., автор {$cooldown-author} сек., сервер {$cooldown-guild} в минуту
     {"*"}{"*"}Запретные:{"*"}{"*"}
     {$self-words}
     {"*"}{"*"}Выгоняющие:{"*"}{"*"}
     {$target-words}
+    {"*"}{"*"}Разрешенные:{"*"}{"*"}
+    {$exceptions}
     {"*"}{"*"}Псевдонимы:{"*"}{"*"}
     {$aliases}
+    {"*"}{"*"}Автоматические псевдонимы:{"*"}{"*"}
+    {$derived-aliases}
 guilds-voice-config-red-alert-command-list-record-single = - {$record}
 guilds-voice-config-red-alert-command-list-record-double = - {$record-start}: {$record-end}
+guilds-voice-config-red-alert-command-list-record-options = {$record} ({$options})
+guilds-voice-config-red-alert-command-list-option = {$option}: {$value}
 guilds-voice-config-red-alert-command-auto-track-add = АВТОСЛЕЖЕНИЕ ДЛЯ ЭТОГО СЕРВЕРА __ВКЛЮЧЕНО__!
-guilds-voice-config-red-alert-command-auto-track-remove = АВТОСЛЕЖЕНИЕ ДЛЯ ЭТОГО СЕРВЕРА __ВЫКЛЮЧЕНО__!
\ No newline at end of file
+guilds-voice-config-red-alert-command-auto-track-remove = АВТОСЛЕЖЕНИЕ ДЛЯ ЭТОГО СЕРВЕРА __ВЫКЛЮЧЕНО__!
+phrase-test-red-alert-command-prefix-anchor = код красный проверка фразы
+phrase-test-red-alert-command-header-suffix = {"{"}упоминание говорящего{"}"}? {"{"}фраза{"}"}
+phrase-test-red-alert-command-help-description = Проверяет фразу по голосовым настройкам сервера без исключения пользователей: показывает сработавшие и почти сработавшие правила, сходство слов, проверку числа букв и пороги.
+phrase-test-red-alert-command-no-access = АТДЫХАЙ, У ТЕБЯ НЕТУ ДОСТУПА!
+phrase-test-red-alert-command-empty-phrase = ЧТО ПРОВЕРЯТЬ?
+phrase-test-red-alert-command-no-matches = НИ ОДНО ПРАВИЛО НЕ СРАБОТАЛО И ДАЖЕ НЕ БЫЛО БЛИЗКО!
+phrase-test-red-alert-command-header = ПРОВЕРКА ФРАЗЫ "{$text}" ОТ {$user-name}:
+phrase-test-red-alert-command-rule-record = {$record-number}. "{$rule}" {$targets} - {$status}
+phrase-test-red-alert-command-status-matched = __СРАБОТАЛО__ НА {$similarity-percent}%
+phrase-test-red-alert-command-status-near-miss = ПОЧТИ СРАБОТАЛО
+phrase-test-red-alert-command-status-suppressed = ОТМЕНЕНО РАЗРЕШЕННОЙ ФРАЗОЙ "{$exception}"
+phrase-test-red-alert-command-phrase-record = {"  "}"{$phrase}": {$similarity-percent}% (ПОРОГ {$threshold-percent}%) {$status}
+phrase-test-red-alert-command-pattern-record = {"  "}ШАБЛОН {$status}
+phrase-test-red-alert-command-word-record = {"    "}"{$query-word}" ~ "{$text-word}": {$similarity-percent}% (ПОРОГ {$threshold-percent}%) {$similarity-status}, РАЗНИЦА БУКВ {$letters-difference} (МАКС. {$letters-threshold}) {$letters-status}
+phrase-test-red-alert-command-word-missing = {"    "}"{$query-word}": НЕТ ПОДХОДЯЩИХ СЛОВ
+phrase-test-red-alert-command-check-passed = ✅
+phrase-test-red-alert-command-check-failed = ❌
+near-misses-red-alert-command-prefix-anchor = код красный промахи
+near-misses-red-alert-command-header-suffix = {"{"}номера промахов{"}"}*/очистить
+near-misses-red-alert-command-help-description =
+    {"*"} - может быть несколько (через пробел).
+    Выводит фразы которые почти сработали (в пределах запаса промаха из настроек голоса) и предлагает погрешность которая бы их поймала.
+    С номерами промахов которые должны были сработать предлагает погрешность для них и показывает какие другие промахи тоже начнут срабатывать.
+near-misses-red-alert-command-no-access = АТДЫХАЙ, У ТЕБЯ НЕТУ ДОСТУПА!
+near-misses-red-alert-command-clear-action = очистить
+near-misses-red-alert-command-clear-success = ПРОМАХИ ОЧИЩЕНЫ!
+near-misses-red-alert-command-empty-list = ПРОМАХОВ ПОКА НЕТ (ИЛИ ЗАПАС ПРОМАХА НЕ НАСТРОЕН)!
+near-misses-red-alert-command-list-header = ПОЧТИ СРАБОТАВШИЕ ФРАЗЫ:
+near-misses-red-alert-command-record = {$record-number}. [ВРЕМЯ: {$time}] {$author-name} "{$text}" ~ "{$rule}" {$targets}: {$similarity-percent}% (ПОРОГ {$threshold-percent}%)
+near-misses-red-alert-command-all-suggestion = ЧТОБЫ ПОЙМАТЬ ВСЕ ПРОМАХИ НУЖНА ПОГРЕШНОСТЬ: {$threshold}. УКАЖИ НОМЕРА ПРОМАХОВ КОТОРЫЕ ДОЛЖНЫ БЫЛИ СРАБОТАТЬ ДЛЯ ТОЧНОГО ПОДБОРА.
+near-misses-red-alert-command-unreachable-suggestion = ЭТИ ПРОМАХИ НЕ ПОЙМАТЬ ПОГРЕШНОСТЬЮ СЕРВЕРА, У ИХ ФРАЗ СВОЯ ПОГРЕШНОСТЬ ИЛИ ШАБЛОН!
+near-misses-red-alert-command-selected-suggestion = ЧТОБЫ ПОЙМАТЬ ВЫБРАННЫЕ ПРОМАХИ ({$count}) НУЖНА ПОГРЕШНОСТЬ: {$threshold}.
+near-misses-red-alert-command-no-false-positives = ЛИШНИХ СРАБАТЫВАНИЙ НЕ БУДЕТ!
+near-misses-red-alert-command-false-positives-header = ТАКЖЕ НАЧНУТ СРАБАТЫВАТЬ:
+red-alert-audit-log-reason-voice = Красная тревога: голосом пользователя {$author-name} ({$author-id}), фраза "{$phrase}"
+red-alert-audit-log-reason-text = Красная тревога: текстовой командой пользователя {$author-name} ({$author-id})
+red-alert-notification-message =
+    КРАСНАЯ ТРЕВОГА НА СЕРВЕРЕ {$guild-name}! К ВАМ ПРИМЕНЕНО НАКАЗАНИЕ: {$action}.
+    ПРИЧИНА: {$cause}
+    {$appeal}
+red-alert-notification-voice-cause = ФРАЗА "{$phrase}"
+red-alert-notification-text-cause = ТЕКСТОВАЯ КОМАНДА МИРОТВОРЦА {$author-name}
+red-alert-notification-default-appeal = ЕСЛИ ЭТО ОШИБКА, ОБРАТИТЕСЬ К АДМИНИСТРАЦИИ СЕРВЕРА.
+red-alert-notification-fallback-message =
+    {$target-name}, ЛИЧНЫЕ СООБЩЕНИЯ ЗАКРЫТЫ, ПОЭТОМУ УВЕДОМЛЕНИЕ ЗДЕСЬ:
+    {$message}
+red-alert-action-warn = ПРЕДУПРЕЖДЕНИЕ
+red-alert-action-warn-message = ПРЕДУПРЕЖДЕНИЕ ОТ КРИНЖ КИЛЛЕРА НА СЕРВЕРЕ {$guild-name}! В СЛЕДУЮЩИЙ РАЗ НАКАЗАНИЕ БУДЕТ СТРОЖЕ!
+red-alert-action-disconnect = ИСКЛЮЧЕНИЕ ИЗ КАНАЛА
+red-alert-action-mute = ОТКЛЮЧЕНИЕ МИКРОФОНА
+red-alert-action-deafen = ОТКЛЮЧЕНИЕ ЗВУКА
+red-alert-action-move = ПЕРЕНОС В {$channel-name}
+red-alert-action-timeout = ТАЙМ-АУТ НА {$minutes} МИН.
+red-alert-action-temporary-role = РОЛЬ {$role-name} НА {$minutes} МИН.
+offenses-red-alert-command-prefix-anchor = код красный нарушения
+offenses-red-alert-command-header-suffix = очистить? {"{"}ID или упоминание пользователя{"}"}?
+offenses-red-alert-command-help-description = Выводит число нарушений пользователей (в пределах окна эскалации) и их следующее наказание. С "очистить" сбрасывает нарушения пользователя или всего сервера.
+offenses-red-alert-command-no-access = АТДЫХАЙ, У ТЕБЯ НЕТУ ДОСТУПА!
+offenses-red-alert-command-incorrect-params = НЕПРАВИЛЬНЫЙ ПОЛЬЗОВАТЕЛЬ! УКАЖИ ID ИЛИ УПОМИНАНИЕ.
+offenses-red-alert-command-clear-action = очистить
+offenses-red-alert-command-clear-success = НАРУШЕНИЯ СБРОШЕНЫ!
+offenses-red-alert-command-empty-list = НАРУШЕНИЙ НЕТ, ВСЕ ЧИСТЕНЬКИЕ!
+offenses-red-alert-command-list-header = НАРУШИТЕЛИ:
+offenses-red-alert-command-record = {$record-number}. {$user-name}: НАРУШЕНИЙ {$count}, СЛЕДУЮЩЕЕ НАКАЗАНИЕ: {$next-action}
+red-alert-rate-limit-target = ЕГО УЖЕ НЕДАВНО НАКАЗЫВАЛИ, ЕЩЕ {$seconds} СЕК.
+red-alert-rate-limit-author = МИРОТВОРЕЦ ОСТЫВАЕТ, ЕЩЕ {$seconds} СЕК.
+red-alert-rate-limit-guild = СЛИШКОМ МНОГО НАКАЗАНИЙ НА СЕРВЕРЕ, ЕЩЕ {$seconds} СЕК.
+red-alert-preflight-guild-not-cached = СЕРВЕР ЕЩЕ НЕ ЗАГРУЖЕН...
+red-alert-preflight-channel-not-found = КАНАЛ НЕ НАЙДЕН...
+red-alert-preflight-missing-permissions = НЕ ХВАТАЕТ ПРАВ: {$permissions}...
+red-alert-permission-view-channel = Просмотр каналов
+red-alert-permission-connect = Подключаться
+red-alert-permission-send-messages = Отправлять сообщения
+red-alert-permission-move-members = Перемещать участников
+red-alert-permission-mute-members = Отключать участникам микрофон
+red-alert-permission-deafen-members = Отключать участникам звук
+red-alert-permission-moderate-members = Отправить участников подумать о своём поведении
+red-alert-permission-manage-roles = Управлять ролями
+red-alert-vote-message = ГОЛОСУЕМ ЗА НАКАЗАНИЕ ({$action}) КРИНЖОВИКА {$target-name} ЗА ФРАЗУ "{$reason-text}"! НУЖНО {$votes} {$emoji} ОТ УЧАСТНИКОВ {$channel-name} ЗА {$seconds} СЕК.
+red-alert-vote-result = ГОЛОСОВАНИЕ ЗА НАКАЗАНИЕ КРИНЖОВИКА {$target-name} ЗАВЕРШЕНО: {$outcome}
+red-alert-vote-outcome-confirmed = ПОДТВЕРЖДЕНО ({$votes} ГОЛОС(ОВ))
+red-alert-vote-outcome-expired = ВРЕМЯ ВЫШЛО ({$votes} ГОЛОС(ОВ))
+red-alert-vote-outcome-failed = НЕ УДАЛОСЬ ПРОВЕСТИ
+red-alert-countdown-message = КРИНЖОВИК {$target-name} БУДЕТ НАКАЗАН ЧЕРЕЗ {$seconds} СЕК.!
+red-alert-countdown-cancel-button = ОТМЕНИТЬ
+red-alert-countdown-no-access = АТДЫХАЙ, У ТЕБЯ НЕТУ ДОСТУПА!
+red-alert-countdown-cancelled = НАКАЗАНИЕ КРИНЖОВИКА {$target-name} ОТМЕНЕНО: {$reason}
+red-alert-countdown-elapsed = КРИНЖОВИК {$target-name} НАКАЗАН!
+red-alert-countdown-cancel-reason-phrase = ФРАЗОЙ ОТМЕНЫ
+red-alert-countdown-cancel-reason-button = КНОПКОЙ РЕДАКТОРА {$user-name}
+red-alert-countdown-cancel-reason-command = КОМАНДОЙ РЕДАКТОРА {$user-name}
+pending-actions-red-alert-command-prefix-anchor = код красный отсчет
+pending-actions-red-alert-command-header-suffix = {"{"}ID или упоминание пользователя{"}"}?
+pending-actions-red-alert-command-help-description = Выводит наказания которые ждут окончания отсчета, с пользователем - отменяет его наказание.
+pending-actions-red-alert-command-no-access = АТДЫХАЙ, У ТЕБЯ НЕТУ ДОСТУПА!
+pending-actions-red-alert-command-list-header = ОЖИДАЮТ НАКАЗАНИЯ:
+pending-actions-red-alert-command-record = {$record-number}. {$target-name} ОТ МИРОТВОРЦA {$author-name}: {$action} ЧЕРЕЗ {$seconds} СЕК.
+pending-actions-red-alert-command-empty-list = НИКТО НЕ ОЖИДАЕТ НАКАЗАНИЯ!
+pending-actions-red-alert-command-cancel-success = НАКАЗАНИЕ {$user-name} ОТМЕНЕНО!
+pending-actions-red-alert-command-not-found = У {$user-name} НЕТ ОЖИДАЮЩЕГО НАКАЗАНИЯ!
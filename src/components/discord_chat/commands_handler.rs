@@ -1,12 +1,31 @@
-use serenity::model::id::GuildId;
+use serenity::model::id::{GuildId, UserId};
 use serenity::model::prelude::ChannelId;
-use serenity::model::prelude::{Message, Ready};
+use serenity::model::prelude::{Guild, GuildMembersChunkEvent, Member, Message, Ready, VoiceState};
 use serenity::model::user::User;
 use serenity::prelude::{Context, EventHandler};
+use std::collections::HashMap;
 
 #[async_trait]
 pub trait OnReady {
     async fn process(&self, ctx: Context, ready: Ready);
+    async fn process_cache_ready(&self, _ctx: Context, _guilds_ids: Vec<GuildId>) {}
+}
+
+#[async_trait]
+pub trait OnGuildMembersUpdate {
+    async fn process(&self, ctx: Context, guild_id: GuildId);
+}
+
+#[async_trait]
+pub trait OnVoiceStateUpdate {
+    async fn process(&self, ctx: Context, voice_state: VoiceState);
+    async fn process_guild_create(
+        &self,
+        _ctx: Context,
+        _guild_id: GuildId,
+        _voice_states: &HashMap<UserId, VoiceState>,
+    ) {
+    }
 }
 
 #[derive(Clone)]
@@ -20,6 +39,8 @@ pub struct CommandParams<'a> {
     pub channel_id: ChannelId,
     pub author: User,
     pub args: &'a [String],
+    /// The same words as `args` before lowercasing.
+    pub raw_args: &'a [String],
 }
 
 #[async_trait]
@@ -38,6 +59,8 @@ pub trait HelpCommandFactory {
 
 pub struct Handler {
     pub on_ready: Box<dyn OnReady + Send + Sync + 'static>,
+    pub on_guild_members_update: Box<dyn OnGuildMembersUpdate + Send + Sync + 'static>,
+    pub on_voice_state_update: Box<dyn OnVoiceStateUpdate + Send + Sync + 'static>,
     pub commands: Vec<Box<dyn Command + Send + Sync + 'static>>,
     pub help_command_factory: Box<dyn HelpCommandFactory + Send + Sync + 'static>,
 }
@@ -48,10 +71,16 @@ impl EventHandler for Handler {
         if msg.author.bot {
             return;
         };
-        fn args(string: String) -> Vec<String> {
+        fn raw_args(string: &str) -> Vec<String> {
             string
                 .split(char::is_whitespace)
-                .map(str::to_lowercase)
+                .map(str::to_owned)
+                .collect()
+        }
+        fn args(string: &str) -> Vec<String> {
+            raw_args(string)
+                .iter()
+                .map(|arg| arg.to_lowercase())
                 .collect()
         }
         let help_command = self.help_command_factory.help_command(
@@ -73,12 +102,13 @@ impl EventHandler for Handler {
             let mut args_commands =
                 Vec::<(Vec<String>, &Box<dyn Command + Send + Sync + 'static>)>::new();
             for command in commands {
-                args_commands.push((args(command.prefix_anchor()), command))
+                args_commands.push((args(&command.prefix_anchor()), command))
             }
             args_commands.sort_by(|f, s| s.0.len().partial_cmp(&f.0.len()).unwrap());
             args_commands
         };
-        let content_args = args(msg.content);
+        let content_args = args(&msg.content);
+        let content_raw_args = raw_args(&msg.content);
         for (command_args, command) in args_commands {
             let Some(args) = content_args.strip_prefix(&command_args[..]) else {
                 continue;
@@ -88,6 +118,7 @@ impl EventHandler for Handler {
                 channel_id: msg.channel_id,
                 author: msg.author,
                 args,
+                raw_args: &content_raw_args[command_args.len()..],
             };
             command.process(ctx, params).await;
             break;
@@ -96,4 +127,38 @@ impl EventHandler for Handler {
     async fn ready(&self, ctx: Context, ready: Ready) {
         self.on_ready.process(ctx, ready).await;
     }
+    async fn cache_ready(&self, ctx: Context, guilds_ids: Vec<GuildId>) {
+        self.on_ready.process_cache_ready(ctx, guilds_ids).await;
+    }
+    async fn voice_state_update(&self, ctx: Context, _: Option<VoiceState>, new: VoiceState) {
+        self.on_voice_state_update.process(ctx, new).await;
+    }
+    async fn guild_create(&self, ctx: Context, guild: Guild, _: bool) {
+        self.on_voice_state_update
+            .process_guild_create(ctx.clone(), guild.id, &guild.voice_states)
+            .await;
+        self.on_guild_members_update.process(ctx, guild.id).await;
+    }
+    async fn guild_members_chunk(&self, ctx: Context, chunk: GuildMembersChunkEvent) {
+        self.on_guild_members_update
+            .process(ctx, chunk.guild_id)
+            .await;
+    }
+    async fn guild_member_addition(&self, ctx: Context, new_member: Member) {
+        self.on_guild_members_update
+            .process(ctx, new_member.guild_id)
+            .await;
+    }
+    async fn guild_member_update(&self, ctx: Context, _: Option<Member>, new: Member) {
+        self.on_guild_members_update.process(ctx, new.guild_id).await;
+    }
+    async fn guild_member_removal(
+        &self,
+        ctx: Context,
+        guild_id: GuildId,
+        _: User,
+        _: Option<Member>,
+    ) {
+        self.on_guild_members_update.process(ctx, guild_id).await;
+    }
 }
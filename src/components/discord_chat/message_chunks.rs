@@ -0,0 +1,78 @@
+use serenity::model::prelude::ChannelId;
+use serenity::prelude::Context;
+
+/// Discord rejects longer messages.
+pub const MESSAGE_MAX_LENGTH: usize = 2000;
+
+/// Splits the text into messages Discord accepts, by lines where possible.
+pub fn message_chunks(text: &str) -> Vec<String> {
+    let mut chunks: Vec<String> = vec![];
+    let mut chunk: Option<(String, usize)> = None;
+    for line in text.split('\n') {
+        let line_chars: Vec<char> = line.chars().collect();
+        let mut line_parts: Vec<String> = line_chars
+            .chunks(MESSAGE_MAX_LENGTH)
+            .map(|part| part.iter().collect())
+            .collect();
+        if line_parts.is_empty() {
+            line_parts.push(String::new());
+        }
+        for part in line_parts {
+            let part_length = part.chars().count();
+            match &mut chunk {
+                Some((chunk_text, chunk_length))
+                    if *chunk_length + 1 + part_length <= MESSAGE_MAX_LENGTH =>
+                {
+                    chunk_text.push('\n');
+                    chunk_text.push_str(&part);
+                    *chunk_length += 1 + part_length;
+                }
+                _ => {
+                    if let Some((chunk_text, _)) = chunk.take() {
+                        chunks.push(chunk_text);
+                    }
+                    chunk = Some((part, part_length));
+                }
+            }
+        }
+    }
+    if let Some((chunk_text, _)) = chunk {
+        chunks.push(chunk_text);
+    }
+    chunks
+}
+
+/// Sends the text as several messages if it doesn't fit into one.
+pub async fn say_chunks(ctx: &Context, channel_id: ChannelId, text: &str) {
+    for chunk in message_chunks(text) {
+        if let Err(error) = channel_id.say(ctx, chunk).await {
+            warn!(
+                "Message to channel {} sending error: {}.",
+                channel_id, error
+            );
+            return;
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn message_chunks_fit_discord_limit() {
+        assert_eq!(message_chunks("a\nb"), vec!["a\nb"]);
+        let line = "б".repeat(1500);
+        let text = [line.as_str(), line.as_str(), "в"].join("\n");
+        let chunks = message_chunks(&text);
+        assert_eq!(chunks, vec![line.clone(), format!("{}\nв", line)]);
+        let chunks = message_chunks(&"г".repeat(4500));
+        assert_eq!(
+            chunks
+                .iter()
+                .map(|chunk| chunk.chars().count())
+                .collect::<Vec<usize>>(),
+            vec![2000, 2000, 500]
+        );
+    }
+}
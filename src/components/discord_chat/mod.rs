@@ -1,5 +1,7 @@
 mod commands_handler;
+mod message_chunks;
 mod print_text_command;
 
 pub use commands_handler::*;
+pub use message_chunks::*;
 pub use print_text_command::*;
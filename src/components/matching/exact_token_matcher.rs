@@ -0,0 +1,13 @@
+use super::*;
+
+pub struct ExactTokenPhraseMatcher;
+
+impl PhraseMatcher for ExactTokenPhraseMatcher {
+    fn words_similarity(&self, query_word: &str, text_word: &str) -> f32 {
+        if query_word == text_word {
+            1.0
+        } else {
+            0.0
+        }
+    }
+}
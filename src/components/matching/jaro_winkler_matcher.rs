@@ -0,0 +1,72 @@
+use super::*;
+
+fn jaro_similarity(first: &[char], second: &[char]) -> f32 {
+    if first.is_empty() && second.is_empty() {
+        return 1.0;
+    }
+    if first.is_empty() || second.is_empty() {
+        return 0.0;
+    }
+    let match_distance = (first.len().max(second.len()) / 2).saturating_sub(1);
+    let mut first_matches = vec![false; first.len()];
+    let mut second_matches = vec![false; second.len()];
+    let mut matches_count: usize = 0;
+    for (first_index, first_char) in first.iter().enumerate() {
+        let start = first_index.saturating_sub(match_distance);
+        let end = (first_index + match_distance + 1).min(second.len());
+        for second_index in start..end {
+            if second_matches[second_index] || second[second_index] != *first_char {
+                continue;
+            }
+            first_matches[first_index] = true;
+            second_matches[second_index] = true;
+            matches_count += 1;
+            break;
+        }
+    }
+    if matches_count == 0 {
+        return 0.0;
+    }
+    let mut transpositions_count: usize = 0;
+    let mut second_index: usize = 0;
+    for (first_index, first_char) in first.iter().enumerate() {
+        if !first_matches[first_index] {
+            continue;
+        }
+        while !second_matches[second_index] {
+            second_index += 1;
+        }
+        if *first_char != second[second_index] {
+            transpositions_count += 1;
+        }
+        second_index += 1;
+    }
+    let matches_count = matches_count as f32;
+    (matches_count / (first.len() as f32)
+        + matches_count / (second.len() as f32)
+        + (matches_count - (transpositions_count as f32) / 2.0) / matches_count)
+        / 3.0
+}
+
+pub fn jaro_winkler_similarity(first: &str, second: &str) -> f32 {
+    const PREFIX_SCALE: f32 = 0.1;
+    const MAX_PREFIX_LEN: usize = 4;
+    let first: Vec<char> = first.chars().collect();
+    let second: Vec<char> = second.chars().collect();
+    let jaro_similarity = jaro_similarity(&first, &second);
+    let prefix_len = first
+        .iter()
+        .zip(second.iter())
+        .take(MAX_PREFIX_LEN)
+        .take_while(|(first_char, second_char)| first_char == second_char)
+        .count();
+    jaro_similarity + (prefix_len as f32) * PREFIX_SCALE * (1.0 - jaro_similarity)
+}
+
+pub struct JaroWinklerPhraseMatcher;
+
+impl PhraseMatcher for JaroWinklerPhraseMatcher {
+    fn words_similarity(&self, query_word: &str, text_word: &str) -> f32 {
+        jaro_winkler_similarity(query_word, text_word)
+    }
+}
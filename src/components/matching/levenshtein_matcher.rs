@@ -0,0 +1,35 @@
+use super::*;
+
+pub fn levenshtein_distance(first: &[char], second: &[char]) -> usize {
+    let mut previous_row: Vec<usize> = (0..=second.len()).collect();
+    let mut current_row: Vec<usize> = vec![0; second.len() + 1];
+    for (first_index, first_char) in first.iter().enumerate() {
+        current_row[0] = first_index + 1;
+        for (second_index, second_char) in second.iter().enumerate() {
+            let substitution_cost = if first_char == second_char { 0 } else { 1 };
+            current_row[second_index + 1] = (previous_row[second_index] + substitution_cost)
+                .min(previous_row[second_index + 1] + 1)
+                .min(current_row[second_index] + 1);
+        }
+        std::mem::swap(&mut previous_row, &mut current_row);
+    }
+    previous_row[second.len()]
+}
+
+pub fn normalized_levenshtein_similarity(first: &str, second: &str) -> f32 {
+    let first: Vec<char> = first.chars().collect();
+    let second: Vec<char> = second.chars().collect();
+    let max_len = first.len().max(second.len());
+    if max_len == 0 {
+        return 1.0;
+    }
+    1.0 - (levenshtein_distance(&first, &second) as f32) / (max_len as f32)
+}
+
+pub struct LevenshteinPhraseMatcher;
+
+impl PhraseMatcher for LevenshteinPhraseMatcher {
+    fn words_similarity(&self, query_word: &str, text_word: &str) -> f32 {
+        normalized_levenshtein_similarity(query_word, text_word)
+    }
+}
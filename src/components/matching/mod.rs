@@ -0,0 +1,116 @@
+mod exact_token_matcher;
+mod jaro_winkler_matcher;
+mod levenshtein_matcher;
+mod ngram_matcher;
+mod phonetic_matcher;
+mod phrase_matcher;
+mod russian_phonetics;
+mod search_phrase;
+mod spoken_names;
+mod text_normalization;
+
+pub use exact_token_matcher::*;
+pub use jaro_winkler_matcher::*;
+pub use levenshtein_matcher::*;
+pub use ngram_matcher::*;
+pub use phonetic_matcher::*;
+pub use phrase_matcher::*;
+pub use russian_phonetics::*;
+pub use search_phrase::*;
+pub use spoken_names::*;
+pub use text_normalization::*;
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    struct NearMissCase {
+        text: &'static str,
+        query: &'static str,
+        threshold: f32,
+        /// Expected phrase for every matcher in `PhraseMatcherKind::ALL` order.
+        expected: [Option<&'static str>; 5],
+    }
+
+    const NEAR_MISS_CASES: [NearMissCase; 6] = [
+        NearMissCase {
+            text: "эй вадим уходи отсюда",
+            query: "вадим уходи",
+            threshold: 0.8,
+            expected: [Some("вадим уходи"); 5],
+        },
+        NearMissCase {
+            text: "Ёлки палки опять",
+            query: "елки палки",
+            threshold: 0.8,
+            expected: [Some("елки палки"); 5],
+        },
+        NearMissCase {
+            text: "позовите фатим",
+            query: "вадим",
+            threshold: 0.7,
+            expected: [None, None, Some("фатим"), None, Some("фатим")],
+        },
+        NearMissCase {
+            text: "уходи вадим",
+            query: "вадим уходи",
+            threshold: 0.8,
+            expected: [None; 5],
+        },
+        NearMissCase {
+            text: "привет как дела",
+            query: "вадим",
+            threshold: 0.5,
+            expected: [None; 5],
+        },
+        NearMissCase {
+            text: "вадимович пришел",
+            query: "вадим",
+            threshold: 0.8,
+            expected: [None; 5],
+        },
+    ];
+
+    #[test]
+    fn every_matcher_passes_near_miss_cases() {
+        let normalization = TextNormalization::default();
+        for case in &NEAR_MISS_CASES {
+            let text = normalization.normalize(case.text);
+            let text_words: Vec<&str> = text.words.iter().map(String::as_str).collect();
+            let query = normalization.normalize_phrase(case.query);
+            for (kind, expected) in PhraseMatcherKind::ALL.iter().zip(case.expected) {
+                let phrase_match = kind.matcher().search(&text_words, &query, case.threshold);
+                assert_eq!(
+                    phrase_match
+                        .as_ref()
+                        .map(|phrase_match| phrase_match.real_phrase.as_str()),
+                    expected,
+                    "{} matcher, query \"{}\" in \"{}\"",
+                    kind.name(),
+                    case.query,
+                    case.text
+                );
+            }
+        }
+    }
+
+    #[test]
+    fn ngram_similarity_is_symmetric_and_exact_for_equal_words() {
+        assert_eq!(ngram_similarity("вадим", "вадим"), 1.0);
+        assert_eq!(
+            ngram_similarity("вадим", "фатим"),
+            ngram_similarity("фатим", "вадим")
+        );
+        assert!(ngram_similarity("вадим", "фатим") < ngram_similarity("вадим", "вадик"));
+    }
+
+    #[test]
+    fn search_pattern_skips_empty_matches() {
+        let phrase = SearchPhrase::try_from(String::from(r"/\d*/")).unwrap();
+        let phrase_match = phrase.search_pattern(&["код", "42", "тревога"]).unwrap();
+        assert_eq!(phrase_match.real_phrase, "42");
+        assert_eq!(phrase_match.span, 1..2);
+        let phrase = SearchPhrase::try_from(String::from(r"/x*/")).unwrap();
+        assert!(phrase.search_pattern(&["код", "красный"]).is_none());
+    }
+}
@@ -0,0 +1,99 @@
+use super::*;
+use ngrammatic::{Corpus, CorpusBuilder};
+use std::collections::{HashMap, HashSet};
+
+const NGRAM_ARITY: usize = 2;
+const NGRAM_WARP: f32 = 2.0;
+
+fn ngrams(word: &str) -> HashMap<String, usize> {
+    let padding = " ".repeat(NGRAM_ARITY - 1);
+    let letters: Vec<char> = format!("{}{}{}", padding, word, padding).chars().collect();
+    let mut ngrams = HashMap::new();
+    for window in letters.windows(NGRAM_ARITY) {
+        *ngrams.entry(window.iter().collect()).or_insert(0) += 1;
+    }
+    ngrams
+}
+
+/// Same warped bigram similarity as the ngrammatic corpus search, without building a corpus.
+pub fn ngram_similarity(first: &str, second: &str) -> f32 {
+    let first_ngrams = ngrams(first);
+    let second_ngrams = ngrams(second);
+    let shared_count: usize = first_ngrams
+        .iter()
+        .map(|(ngram, count)| second_ngrams.get(ngram).map_or(0, |other| *other.min(count)))
+        .sum();
+    let all_count = first_ngrams.values().sum::<usize>() + second_ngrams.values().sum::<usize>()
+        - shared_count;
+    let all_warped = (all_count as f32).powf(NGRAM_WARP);
+    (all_warped - ((all_count - shared_count) as f32).powf(NGRAM_WARP)) / all_warped
+}
+
+pub struct NgramPhraseMatcher;
+
+impl PhraseMatcher for NgramPhraseMatcher {
+    fn words_similarity(&self, query_word: &str, text_word: &str) -> f32 {
+        ngram_similarity(query_word, text_word)
+    }
+}
+
+/// Corpus of the query words built once, so every text word is compared with all of them
+/// by a single search instead of building a corpus for every pair of words.
+pub struct NgramWordsIndex {
+    corpus: Corpus,
+    words: HashSet<String>,
+}
+
+impl NgramWordsIndex {
+    pub fn new<'a>(query_words: impl IntoIterator<Item = &'a str>) -> Self {
+        let mut corpus = CorpusBuilder::new().finish();
+        let mut words = HashSet::new();
+        for query_word in query_words {
+            if words.insert(query_word.to_owned()) {
+                corpus.add_text(query_word);
+            }
+        }
+        Self { corpus, words }
+    }
+    pub fn matcher(&self, text_words: &[&str]) -> NgramIndexedPhraseMatcher {
+        let mut similarities: HashMap<String, HashMap<String, f32>> = HashMap::new();
+        for text_word in text_words {
+            if similarities.contains_key(*text_word) {
+                continue;
+            }
+            let text_word_similarities = self
+                .corpus
+                .search(text_word, 0.0)
+                .into_iter()
+                .map(|search_result| (search_result.text, search_result.similarity))
+                .collect();
+            similarities.insert(text_word.to_string(), text_word_similarities);
+        }
+        NgramIndexedPhraseMatcher {
+            index: self,
+            similarities,
+        }
+    }
+}
+
+/// N-gram matcher over the text words precomputed with `NgramWordsIndex`. Query words
+/// missing in the index fall back to `NgramPhraseMatcher`.
+pub struct NgramIndexedPhraseMatcher<'a> {
+    index: &'a NgramWordsIndex,
+    similarities: HashMap<String, HashMap<String, f32>>,
+}
+
+impl<'a> PhraseMatcher for NgramIndexedPhraseMatcher<'a> {
+    fn words_similarity(&self, query_word: &str, text_word: &str) -> f32 {
+        if !self.index.words.contains(query_word) {
+            return NgramPhraseMatcher.words_similarity(query_word, text_word);
+        }
+        let Some(text_word_similarities) = self.similarities.get(text_word) else {
+            return NgramPhraseMatcher.words_similarity(query_word, text_word);
+        };
+        text_word_similarities
+            .get(query_word)
+            .copied()
+            .unwrap_or(0.0)
+    }
+}
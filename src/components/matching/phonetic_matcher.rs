@@ -0,0 +1,42 @@
+use super::*;
+
+pub fn phonetic_similarity(first: &str, second: &str) -> f32 {
+    let first_key = russian_phonetic_key(first);
+    let second_key = russian_phonetic_key(second);
+    let max_len = first_key.len().max(second_key.len());
+    if max_len == 0 {
+        return 1.0;
+    }
+    1.0 - (levenshtein_distance(&first_key, &second_key) as f32) / (max_len as f32)
+}
+
+pub struct PhoneticPhraseMatcher;
+
+impl PhraseMatcher for PhoneticPhraseMatcher {
+    fn words_similarity(&self, query_word: &str, text_word: &str) -> f32 {
+        phonetic_similarity(query_word, text_word)
+    }
+}
+
+/// Mixes similarity of the base matcher with the phonetic similarity, so spelling variants
+/// of the same sounding words are penalized less.
+pub struct PhoneticBlendPhraseMatcher<'a> {
+    pub base: &'a (dyn PhraseMatcher + Send + Sync),
+    pub phonetic_weight: f32,
+}
+
+impl<'a> PhraseMatcher for PhoneticBlendPhraseMatcher<'a> {
+    fn words_similarity(&self, query_word: &str, text_word: &str) -> f32 {
+        let phonetic_weight = self.phonetic_weight.min(1.0).max(0.0);
+        if phonetic_weight == 0.0 {
+            return self.base.words_similarity(query_word, text_word);
+        }
+        let base_similarity = if phonetic_weight < 1.0 {
+            self.base.words_similarity(query_word, text_word)
+        } else {
+            0.0
+        };
+        base_similarity * (1.0 - phonetic_weight)
+            + phonetic_similarity(query_word, text_word) * phonetic_weight
+    }
+}
@@ -0,0 +1,162 @@
+use super::*;
+use serde::{Deserialize, Serialize};
+use std::ops::Range;
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct PhraseMatch {
+    pub real_phrase: String,
+    pub similarity: f32,
+    pub span: Range<usize>,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct WordMatchReport {
+    pub query_word: String,
+    pub text_word: Option<String>,
+    pub similarity: f32,
+    pub similarity_threshold: f32,
+    pub letters_count_difference: usize,
+    pub letters_count_threshold: usize,
+}
+
+impl WordMatchReport {
+    pub fn is_similarity_passed(&self) -> bool {
+        self.text_word.is_some() && self.similarity >= self.similarity_threshold
+    }
+    pub fn is_letters_count_passed(&self) -> bool {
+        self.text_word.is_some() && self.letters_count_difference <= self.letters_count_threshold
+    }
+}
+
+/// Details of the phrase search: how every query word was paired with the text and why the
+/// phrase was or was not found.
+#[derive(Debug, Clone, PartialEq)]
+pub struct PhraseMatchReport {
+    pub words: Vec<WordMatchReport>,
+    pub similarity: f32,
+    pub similarity_threshold: f32,
+    pub phrase_match: Option<PhraseMatch>,
+}
+
+pub trait PhraseMatcher {
+    /// Similarity of two single words in range `0.0..=1.0`.
+    fn words_similarity(&self, query_word: &str, text_word: &str) -> f32;
+
+    /// Searches the query phrase in the text words. Every query word is paired with the most
+    /// similar text word, paired words must have close letters count and together must be
+    /// placed in the text in the same order as in the query.
+    fn search(&self, text_words: &[&str], query: &str, threshold: f32) -> Option<PhraseMatch> {
+        self.explain(text_words, query, threshold).phrase_match
+    }
+
+    /// Same as `search`, but reports the pairing of every query word even if the phrase is
+    /// not found.
+    fn explain(&self, text_words: &[&str], query: &str, threshold: f32) -> PhraseMatchReport {
+        let query_words: Vec<&str> = query.split_whitespace().collect();
+        let mut report = PhraseMatchReport {
+            words: vec![],
+            similarity: 0.0,
+            similarity_threshold: threshold,
+            phrase_match: None,
+        };
+        if query_words.is_empty() {
+            return report;
+        }
+        let single_word_threshold = threshold / (query_words.len() as f32);
+        let mut real_query_words: Vec<&str> = vec![];
+        let mut total_similarity_sum: f32 = 0.0;
+        for query_word in query_words {
+            let mut best_match: Option<(&str, f32)> = None;
+            for text_word in text_words {
+                let word_similarity = self.words_similarity(query_word, text_word);
+                if let Some((_, best_similarity)) = best_match {
+                    if word_similarity <= best_similarity {
+                        continue;
+                    }
+                }
+                best_match = Some((text_word, word_similarity));
+            }
+            // Counted in bytes as before the matchers were extracted, Cyrillic letters count
+            // twice and configured thresholds keep their meaning.
+            let letters_count = query_word.len();
+            let (real_letters_count, word_similarity) = best_match
+                .map_or((0, 0.0), |(real_query_word, word_similarity)| {
+                    (real_query_word.len(), word_similarity)
+                });
+            let word_report = WordMatchReport {
+                query_word: query_word.to_owned(),
+                text_word: best_match.map(|(real_query_word, _)| real_query_word.to_owned()),
+                similarity: word_similarity,
+                similarity_threshold: single_word_threshold,
+                letters_count_difference: real_letters_count.max(letters_count)
+                    - real_letters_count.min(letters_count),
+                letters_count_threshold: ((real_letters_count as f32) * (1.0 - threshold))
+                    .round() as usize,
+            };
+            if let Some((real_query_word, _)) = best_match {
+                if word_report.is_similarity_passed() && word_report.is_letters_count_passed() {
+                    real_query_words.push(real_query_word);
+                }
+            }
+            total_similarity_sum += word_similarity;
+            report.words.push(word_report);
+        }
+        report.similarity = total_similarity_sum / (report.words.len() as f32);
+        if real_query_words.len() != report.words.len() || report.similarity < threshold {
+            return report;
+        }
+        let Some(start) = text_words
+            .windows(real_query_words.len())
+            .position(|window| window == real_query_words.as_slice()) else {
+            return report;
+        };
+        report.phrase_match = Some(PhraseMatch {
+            real_phrase: real_query_words.join(" "),
+            similarity: report.similarity,
+            span: start..start + real_query_words.len(),
+        });
+        report
+    }
+}
+
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum PhraseMatcherKind {
+    #[default]
+    Ngram,
+    Levenshtein,
+    JaroWinkler,
+    ExactToken,
+    Phonetic,
+}
+
+impl PhraseMatcherKind {
+    pub const ALL: [PhraseMatcherKind; 5] = [
+        PhraseMatcherKind::Ngram,
+        PhraseMatcherKind::Levenshtein,
+        PhraseMatcherKind::JaroWinkler,
+        PhraseMatcherKind::ExactToken,
+        PhraseMatcherKind::Phonetic,
+    ];
+    pub fn name(&self) -> &'static str {
+        match self {
+            PhraseMatcherKind::Ngram => "ngram",
+            PhraseMatcherKind::Levenshtein => "levenshtein",
+            PhraseMatcherKind::JaroWinkler => "jaro_winkler",
+            PhraseMatcherKind::ExactToken => "exact_token",
+            PhraseMatcherKind::Phonetic => "phonetic",
+        }
+    }
+    pub fn from_name(name: &str) -> Option<Self> {
+        Self::ALL.into_iter().find(|kind| kind.name() == name)
+    }
+    pub fn matcher(&self) -> &'static (dyn PhraseMatcher + Send + Sync) {
+        match self {
+            PhraseMatcherKind::Ngram => &NgramPhraseMatcher,
+            PhraseMatcherKind::Levenshtein => &LevenshteinPhraseMatcher,
+            PhraseMatcherKind::JaroWinkler => &JaroWinklerPhraseMatcher,
+            PhraseMatcherKind::ExactToken => &ExactTokenPhraseMatcher,
+            PhraseMatcherKind::Phonetic => &PhoneticPhraseMatcher,
+        }
+    }
+}
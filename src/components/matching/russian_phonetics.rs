@@ -0,0 +1,97 @@
+const LATIN_DIGRAPHS: [(&str, &str); 12] = [
+    ("shch", "щ"),
+    ("sch", "щ"),
+    ("sh", "ш"),
+    ("ch", "ч"),
+    ("zh", "ж"),
+    ("kh", "х"),
+    ("ts", "ц"),
+    ("ya", "я"),
+    ("ja", "я"),
+    ("yu", "ю"),
+    ("ju", "ю"),
+    ("yo", "ё"),
+];
+
+fn latin_letter(letter: char) -> Option<char> {
+    Some(match letter {
+        'a' => 'а',
+        'b' => 'б',
+        'c' => 'к',
+        'd' => 'д',
+        'e' => 'е',
+        'f' => 'ф',
+        'g' => 'г',
+        'h' => 'х',
+        'i' => 'и',
+        'j' => 'й',
+        'k' => 'к',
+        'l' => 'л',
+        'm' => 'м',
+        'n' => 'н',
+        'o' => 'о',
+        'p' => 'п',
+        'q' => 'к',
+        'r' => 'р',
+        's' => 'с',
+        't' => 'т',
+        'u' => 'у',
+        'v' => 'в',
+        'w' => 'в',
+        'x' => 'к',
+        'y' => 'ы',
+        'z' => 'з',
+        _ => return None,
+    })
+}
+
+/// Transliterates Latin letters of the word to Cyrillic, other letters are kept as is.
+pub fn transliterate_latin(word: &str) -> String {
+    let word = word.to_lowercase();
+    let mut result = String::with_capacity(word.len());
+    let mut rest = word.as_str();
+    'letters: while let Some(letter) = rest.chars().next() {
+        for (digraph, replacement) in LATIN_DIGRAPHS {
+            if let Some(stripped) = rest.strip_prefix(digraph) {
+                result.push_str(replacement);
+                rest = stripped;
+                continue 'letters;
+            }
+        }
+        result.push(latin_letter(letter).unwrap_or(letter));
+        rest = &rest[letter.len_utf8()..];
+    }
+    result
+}
+
+/// Builds phonetic key of the Russian (or transliterated Latin) word: vowels are reduced to
+/// three classes, voiced consonants are devoiced, similar sounding consonants and letter
+/// combinations are merged, soft and hard signs are dropped and repeated sounds are collapsed.
+pub fn russian_phonetic_key(word: &str) -> Vec<char> {
+    let word = transliterate_latin(word)
+        .replace("тьс", "ц")
+        .replace("тс", "ц")
+        .replace("дс", "ц")
+        .replace("сч", "щ")
+        .replace("зч", "щ")
+        .replace("жч", "щ");
+    let mut key: Vec<char> = word
+        .chars()
+        .filter_map(|letter| match letter {
+            'а' | 'о' | 'я' => Some('а'),
+            'е' | 'ё' | 'э' | 'и' | 'ы' | 'й' => Some('и'),
+            'у' | 'ю' => Some('у'),
+            'б' | 'п' => Some('п'),
+            'в' | 'ф' => Some('ф'),
+            'г' | 'к' | 'х' => Some('к'),
+            'д' | 'т' => Some('т'),
+            'ж' | 'ш' | 'щ' | 'ч' => Some('ш'),
+            'з' | 'с' | 'ц' => Some('с'),
+            'ь' | 'ъ' => None,
+            letter if letter.is_alphanumeric() => Some(letter),
+            _ => None,
+        })
+        .collect();
+    key.dedup();
+    key
+}
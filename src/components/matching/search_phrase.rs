@@ -0,0 +1,96 @@
+use super::*;
+use regex::Regex;
+use serde::{Deserialize, Serialize};
+use std::fmt;
+
+/// Configured phrase, that can be a plain phrase, a glob pattern with `*` and `?` wildcards
+/// or a regular expression wrapped with slashes (`/красн\w+ тревог\w+/`).
+/// Patterns are compiled once on creation.
+#[derive(Clone, Serialize, Deserialize)]
+#[serde(try_from = "String", into = "String")]
+pub struct SearchPhrase {
+    text: String,
+    pattern: Option<Regex>,
+}
+
+impl SearchPhrase {
+    pub fn text(&self) -> &str {
+        &self.text
+    }
+    pub fn is_pattern(&self) -> bool {
+        self.pattern.is_some()
+    }
+    fn compile_pattern(text: &str) -> Result<Option<Regex>, regex::Error> {
+        if let Some(regex_string) = text
+            .strip_prefix('/')
+            .and_then(|text| text.strip_suffix('/'))
+        {
+            return Regex::new(&format!("(?i){}", regex_string)).map(Some);
+        }
+        if !text.contains(['*', '?']) {
+            return Ok(None);
+        }
+        let mut regex_string = String::from(r"(?i)\b");
+        let mut is_previous_whitespace = false;
+        for letter in text.to_lowercase().replace('ё', "е").chars() {
+            if letter.is_whitespace() {
+                if !is_previous_whitespace {
+                    regex_string.push_str(r"\s+");
+                }
+                is_previous_whitespace = true;
+                continue;
+            }
+            is_previous_whitespace = false;
+            match letter {
+                '*' => regex_string.push_str(r"\S*"),
+                '?' => regex_string.push_str(r"\S"),
+                letter => regex_string.push_str(&regex::escape(&letter.to_string())),
+            }
+        }
+        regex_string.push_str(r"\b");
+        Regex::new(&regex_string).map(Some)
+    }
+    /// Searches the pattern in the text words, `None` is returned for plain phrases.
+    pub fn search_pattern(&self, text_words: &[&str]) -> Option<PhraseMatch> {
+        let pattern = self.pattern.as_ref()?;
+        let text = text_words.join(" ");
+        let (start_byte, matched_text) = pattern.find_iter(&text).find_map(|pattern_match| {
+            let matched_text = pattern_match.as_str().trim_start();
+            let start_byte = pattern_match.end() - matched_text.len();
+            Some((start_byte, matched_text.trim_end())).filter(|(_, text)| !text.is_empty())
+        })?;
+        let start = text[..start_byte].matches(' ').count();
+        let end = start + matched_text.matches(' ').count() + 1;
+        Some(PhraseMatch {
+            real_phrase: text_words[start..end].join(" "),
+            similarity: 1.0,
+            span: start..end,
+        })
+    }
+}
+
+impl TryFrom<String> for SearchPhrase {
+    type Error = regex::Error;
+    fn try_from(text: String) -> Result<Self, Self::Error> {
+        let pattern = Self::compile_pattern(&text)?;
+        Ok(Self { text, pattern })
+    }
+}
+
+impl From<SearchPhrase> for String {
+    fn from(search_phrase: SearchPhrase) -> Self {
+        search_phrase.text
+    }
+}
+
+impl PartialEq for SearchPhrase {
+    fn eq(&self, other: &Self) -> bool {
+        self.text == other.text
+    }
+}
+
+impl fmt::Debug for SearchPhrase {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        fmt::Debug::fmt(&self.text, f)
+    }
+}
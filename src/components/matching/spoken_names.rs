@@ -0,0 +1,64 @@
+use super::*;
+
+const MIN_SPOKEN_NAME_LEN: usize = 3;
+
+const NAMES_DIMINUTIVES: [&[&str]; 32] = [
+    &["александр", "александра", "саша", "шура", "саня", "санек"],
+    &["алексей", "леша", "алеша", "леха"],
+    &["анастасия", "настя", "настена", "ася"],
+    &["анатолий", "толя", "толик"],
+    &["андрей", "андрюша", "андрюха", "дрюня"],
+    &["анна", "аня", "анюта", "нюра"],
+    &["антон", "антоша", "тоха"],
+    &["артем", "тема", "темыч"],
+    &["вадим", "вадик", "дима"],
+    &["валентин", "валя", "валек"],
+    &["василий", "вася", "васек"],
+    &["виктор", "витя", "витек"],
+    &["виталий", "виталик", "витя"],
+    &["владимир", "вова", "володя", "вован"],
+    &["владислав", "влад", "владик", "слава"],
+    &["галина", "галя"],
+    &["григорий", "гриша"],
+    &["даниил", "данила", "даня", "данек"],
+    &["дмитрий", "дима", "димон", "митя"],
+    &["евгений", "евгения", "женя", "жека"],
+    &["екатерина", "катя", "катюша"],
+    &["елена", "лена", "аленка"],
+    &["иван", "ваня", "ванек"],
+    &["кирилл", "киря", "кирюха"],
+    &["константин", "костя", "костян"],
+    &["максим", "макс", "максик"],
+    &["мария", "маша", "маня"],
+    &["михаил", "миша", "мишаня"],
+    &["николай", "коля", "колян"],
+    &["никита", "никитос"],
+    &["сергей", "сережа", "серега"],
+    &["ярослав", "ярик", "слава"],
+];
+
+/// Derives words by which the person with the given nickname or display name could be called
+/// in speech: every word of the name transliterated to Cyrillic with common diminutives.
+pub fn spoken_names(name: &str) -> Vec<String> {
+    let mut names: Vec<String> = vec![];
+    for word in name.split(|letter: char| !letter.is_alphabetic()) {
+        let word = transliterate_latin(word).replace('ё', "е");
+        if word.chars().count() < MIN_SPOKEN_NAME_LEN {
+            continue;
+        }
+        for diminutives in NAMES_DIMINUTIVES {
+            if !diminutives.contains(&word.as_str()) {
+                continue;
+            }
+            for diminutive in diminutives {
+                if !names.iter().any(|name| name == diminutive) {
+                    names.push(diminutive.to_string());
+                }
+            }
+        }
+        if !names.contains(&word) {
+            names.push(word);
+        }
+    }
+    names
+}
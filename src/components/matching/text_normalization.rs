@@ -0,0 +1,271 @@
+use serde::{Deserialize, Serialize};
+use std::ops::Range;
+
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub enum NormalizationLanguage {
+    #[default]
+    #[serde(rename = "ru")]
+    Russian,
+    #[serde(rename = "en")]
+    English,
+}
+
+impl NormalizationLanguage {
+    pub const ALL: [NormalizationLanguage; 2] =
+        [NormalizationLanguage::Russian, NormalizationLanguage::English];
+    pub fn name(&self) -> &'static str {
+        match self {
+            NormalizationLanguage::Russian => "ru",
+            NormalizationLanguage::English => "en",
+        }
+    }
+    pub fn from_name(name: &str) -> Option<Self> {
+        Self::ALL.into_iter().find(|language| language.name() == name)
+    }
+}
+
+#[derive(Debug, Default, Clone, PartialEq, Serialize, Deserialize)]
+pub struct TextNormalization {
+    #[serde(default)]
+    pub language: NormalizationLanguage,
+    #[serde(default)]
+    pub stemming: bool,
+}
+
+pub struct NormalizedText {
+    pub words: Vec<String>,
+    pub source_words: Vec<String>,
+    source_indices: Vec<usize>,
+}
+
+impl NormalizedText {
+    /// Converts span of normalized words to span of source words.
+    pub fn source_span(&self, span: &Range<usize>) -> Range<usize> {
+        if span.start >= span.end {
+            let source_index = self
+                .source_indices
+                .get(span.start)
+                .copied()
+                .unwrap_or(self.source_words.len());
+            return source_index..source_index;
+        }
+        self.source_indices[span.start]..self.source_indices[span.end - 1] + 1
+    }
+    pub fn source_phrase(&self, span: &Range<usize>) -> String {
+        self.source_words[self.source_span(span)].join(" ")
+    }
+}
+
+impl TextNormalization {
+    pub fn normalize(&self, text: &str) -> NormalizedText {
+        let source_words: Vec<String> = text.split_whitespace().map(str::to_owned).collect();
+        let mut words: Vec<String> = vec![];
+        let mut source_indices: Vec<usize> = vec![];
+        for (source_index, source_word) in source_words.iter().enumerate() {
+            let source_word = source_word.to_lowercase();
+            let source_word = match self.language {
+                NormalizationLanguage::Russian => source_word.replace('ё', "е"),
+                NormalizationLanguage::English => source_word,
+            };
+            for word in source_word.split(|letter: char| !letter.is_alphanumeric()) {
+                if word.is_empty() {
+                    continue;
+                }
+                let numeral_words = word
+                    .parse::<u32>()
+                    .ok()
+                    .and_then(|number| number_to_words(number, self.language));
+                let word_parts = if let Some(numeral_words) = numeral_words {
+                    numeral_words
+                } else if self.stemming {
+                    vec![stem(word, self.language)]
+                } else {
+                    vec![word.to_owned()]
+                };
+                for word_part in word_parts {
+                    words.push(word_part);
+                    source_indices.push(source_index);
+                }
+            }
+        }
+        NormalizedText {
+            words,
+            source_words,
+            source_indices,
+        }
+    }
+    pub fn normalize_phrase(&self, phrase: &str) -> String {
+        self.normalize(phrase).words.join(" ")
+    }
+}
+
+const RUSSIAN_UNITS: [&str; 20] = [
+    "ноль",
+    "один",
+    "два",
+    "три",
+    "четыре",
+    "пять",
+    "шесть",
+    "семь",
+    "восемь",
+    "девять",
+    "десять",
+    "одиннадцать",
+    "двенадцать",
+    "тринадцать",
+    "четырнадцать",
+    "пятнадцать",
+    "шестнадцать",
+    "семнадцать",
+    "восемнадцать",
+    "девятнадцать",
+];
+const RUSSIAN_TENS: [&str; 10] = [
+    "",
+    "",
+    "двадцать",
+    "тридцать",
+    "сорок",
+    "пятьдесят",
+    "шестьдесят",
+    "семьдесят",
+    "восемьдесят",
+    "девяносто",
+];
+const RUSSIAN_HUNDREDS: [&str; 10] = [
+    "",
+    "сто",
+    "двести",
+    "триста",
+    "четыреста",
+    "пятьсот",
+    "шестьсот",
+    "семьсот",
+    "восемьсот",
+    "девятьсот",
+];
+const ENGLISH_UNITS: [&str; 20] = [
+    "zero",
+    "one",
+    "two",
+    "three",
+    "four",
+    "five",
+    "six",
+    "seven",
+    "eight",
+    "nine",
+    "ten",
+    "eleven",
+    "twelve",
+    "thirteen",
+    "fourteen",
+    "fifteen",
+    "sixteen",
+    "seventeen",
+    "eighteen",
+    "nineteen",
+];
+const ENGLISH_TENS: [&str; 10] = [
+    "", "", "twenty", "thirty", "forty", "fifty", "sixty", "seventy", "eighty", "ninety",
+];
+
+fn below_thousand_to_words(
+    number: u32,
+    language: NormalizationLanguage,
+    is_feminine: bool,
+) -> Vec<String> {
+    let mut words: Vec<String> = vec![];
+    let hundreds = (number / 100) as usize;
+    let rest = (number % 100) as usize;
+    match language {
+        NormalizationLanguage::Russian => {
+            if hundreds > 0 {
+                words.push(RUSSIAN_HUNDREDS[hundreds].to_owned());
+            }
+            let units = if rest < 20 {
+                rest
+            } else {
+                words.push(RUSSIAN_TENS[rest / 10].to_owned());
+                rest % 10
+            };
+            if units > 0 || (rest == 0 && hundreds == 0) {
+                words.push(match (units, is_feminine) {
+                    (1, true) => "одна".to_owned(),
+                    (2, true) => "две".to_owned(),
+                    _ => RUSSIAN_UNITS[units].to_owned(),
+                });
+            }
+        }
+        NormalizationLanguage::English => {
+            if hundreds > 0 {
+                words.push(ENGLISH_UNITS[hundreds].to_owned());
+                words.push("hundred".to_owned());
+            }
+            let units = if rest < 20 {
+                rest
+            } else {
+                words.push(ENGLISH_TENS[rest / 10].to_owned());
+                rest % 10
+            };
+            if units > 0 || (rest == 0 && hundreds == 0) {
+                words.push(ENGLISH_UNITS[units].to_owned());
+            }
+        }
+    }
+    words
+}
+
+/// Spells the number with words, numbers from one million are not supported.
+fn number_to_words(number: u32, language: NormalizationLanguage) -> Option<Vec<String>> {
+    if number >= 1_000_000 {
+        return None;
+    }
+    let thousands = number / 1000;
+    let rest = number % 1000;
+    let mut words: Vec<String> = vec![];
+    if thousands > 0 {
+        words.extend(below_thousand_to_words(thousands, language, true));
+        words.push(
+            match language {
+                NormalizationLanguage::Russian => {
+                    match (thousands % 100 / 10 == 1, thousands % 10) {
+                        (false, 1) => "тысяча",
+                        (false, 2..=4) => "тысячи",
+                        _ => "тысяч",
+                    }
+                }
+                NormalizationLanguage::English => "thousand",
+            }
+            .to_owned(),
+        );
+    }
+    if rest > 0 || thousands == 0 {
+        words.extend(below_thousand_to_words(rest, language, false));
+    }
+    Some(words)
+}
+
+const RUSSIAN_ENDINGS: &[&str] = &[
+    "иями", "ями", "ами", "ого", "его", "ому", "ему", "ыми", "ими", "иях", "ая", "яя", "ое", "ее",
+    "ие", "ые", "ой", "ей", "ий", "ый", "ую", "юю", "ом", "ем", "ам", "ям", "ах", "ях", "ов", "ев",
+    "а", "я", "о", "е", "у", "ю", "ы", "и",
+];
+const ENGLISH_ENDINGS: &[&str] = &["ing", "ed", "es", "s", "ly"];
+const MIN_STEM_LEN: usize = 3;
+
+/// Strips the longest known inflection ending if the rest of the word is long enough.
+fn stem(word: &str, language: NormalizationLanguage) -> String {
+    let endings = match language {
+        NormalizationLanguage::Russian => RUSSIAN_ENDINGS,
+        NormalizationLanguage::English => ENGLISH_ENDINGS,
+    };
+    endings
+        .iter()
+        .filter_map(|ending| word.strip_suffix(ending))
+        .filter(|stem| stem.chars().count() >= MIN_STEM_LEN)
+        .min_by_key(|stem| stem.len())
+        .unwrap_or(word)
+        .to_owned()
+}
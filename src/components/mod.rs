@@ -2,10 +2,12 @@ mod base;
 mod discord_chat;
 mod discord_voice;
 mod localization;
+mod matching;
 mod voice;
 
 pub use base::*;
 pub use discord_chat::*;
 pub use discord_voice::*;
 pub use localization::*;
+pub use matching::*;
 pub use voice::*;
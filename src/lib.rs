@@ -0,0 +1,13 @@
+#![allow(dead_code)]
+
+pub mod components;
+pub mod red_alert;
+
+#[macro_use]
+extern crate log;
+
+#[macro_use]
+extern crate async_trait;
+
+#[macro_use]
+extern crate fluent;
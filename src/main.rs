@@ -1,16 +1,4 @@
-#![allow(dead_code)]
-
-mod components;
-mod red_alert;
-
-#[macro_use]
-extern crate log;
-
-#[macro_use]
-extern crate async_trait;
-
-#[macro_use]
-extern crate fluent;
+use ::red_alert::{components, red_alert};
 
 #[tokio::main]
 async fn main() {
@@ -21,7 +9,7 @@ async fn main() {
     use songbird::Config as SongbirdConfig;
     use songbird::SerenityInit;
     use std::os::raw::c_int;
-    use std::path::Path;
+    use std::path::{Path, PathBuf};
     use std::sync::Arc;
     use voskrust::api::{set_log_level as set_vosk_log_level, Model as VoskModel};
 
@@ -51,13 +39,21 @@ async fn main() {
         set_vosk_log_level(vosk_log_level as c_int);
     }
 
-    let intents = GatewayIntents::non_privileged() | GatewayIntents::MESSAGE_CONTENT;
+    let sirens_path = settings.get_string("sirens_path").ok().map(PathBuf::from);
+
+    let guild_members_intent = settings.get_bool("guild_members_intent").unwrap_or(false);
+
+    let mut intents = GatewayIntents::non_privileged() | GatewayIntents::MESSAGE_CONTENT;
+    if guild_members_intent {
+        intents |= GatewayIntents::GUILD_MEMBERS;
+    }
     let mut client = Client::builder(&token, intents)
         .event_handler(
             red_alert::RedAlertCommandsHandlerConstructor {
                 recognition_model: VoskModel::new(vosk_model_path.as_str())
                     .expect("Incorrect recognition model!"),
-                red_alert_handler: Arc::new(red_alert::RedAlertHandler),
+                red_alert_handler: Arc::new(red_alert::RedAlertHandler::new(l10n.clone())),
+                sirens_path,
                 l10n,
             }
             .build(),
@@ -0,0 +1,332 @@
+use super::super::components::*;
+use chrono::{offset, Duration as ChronoDuration};
+use serde::{Deserialize, Serialize};
+use serenity::builder::EditMember;
+use serenity::json;
+use serenity::model::id::GuildId;
+use serenity::model::prelude::{ChannelId, Permissions, RoleId, UserId};
+use serenity::prelude::{Context, Mentionable, SerenityError};
+use std::time::Duration;
+
+#[async_trait]
+pub trait RedAlertAction {
+    async fn perform(
+        &self,
+        ctx: &Context,
+        guild_id: &GuildId,
+        user_id: &UserId,
+        reason: &str,
+    ) -> Result<(), SerenityError>;
+}
+
+/// Edits the member with the audit log reason, which the `GuildId` helpers can't pass.
+async fn edit_member<F>(
+    ctx: &Context,
+    guild_id: &GuildId,
+    user_id: &UserId,
+    reason: &str,
+    f: F,
+) -> Result<(), SerenityError>
+where
+    F: FnOnce(&mut EditMember) -> &mut EditMember,
+{
+    let mut edit_member = EditMember::default();
+    f(&mut edit_member);
+    let map = json::hashmap_to_json_map(edit_member.0);
+    ctx.http
+        .edit_member(guild_id.0, user_id.0, &map, Some(reason))
+        .await
+        .map(|_| ())
+}
+
+pub struct RedAlertDisconnectAction;
+
+#[async_trait]
+impl RedAlertAction for RedAlertDisconnectAction {
+    async fn perform(
+        &self,
+        ctx: &Context,
+        guild_id: &GuildId,
+        user_id: &UserId,
+        reason: &str,
+    ) -> Result<(), SerenityError> {
+        edit_member(ctx, guild_id, user_id, reason, |member| {
+            member.disconnect_member()
+        })
+        .await
+    }
+}
+
+pub struct RedAlertWarnAction {
+    pub l10n: L10n,
+}
+
+#[async_trait]
+impl RedAlertAction for RedAlertWarnAction {
+    async fn perform(
+        &self,
+        ctx: &Context,
+        guild_id: &GuildId,
+        user_id: &UserId,
+        _reason: &str,
+    ) -> Result<(), SerenityError> {
+        let guild_name = guild_id
+            .name(&ctx.cache)
+            .unwrap_or_else(|| guild_id.to_string());
+        user_id
+            .create_dm_channel(&ctx)
+            .await?
+            .say(
+                &ctx,
+                self.l10n.string(
+                    "red-alert-action-warn-message",
+                    fluent_args![
+                        "guild-name" => guild_name
+                    ],
+                ),
+            )
+            .await
+            .map(|_| ())
+    }
+}
+
+pub struct RedAlertMuteAction;
+
+#[async_trait]
+impl RedAlertAction for RedAlertMuteAction {
+    async fn perform(
+        &self,
+        ctx: &Context,
+        guild_id: &GuildId,
+        user_id: &UserId,
+        reason: &str,
+    ) -> Result<(), SerenityError> {
+        edit_member(ctx, guild_id, user_id, reason, |member| member.mute(true)).await
+    }
+}
+
+pub struct RedAlertDeafenAction;
+
+#[async_trait]
+impl RedAlertAction for RedAlertDeafenAction {
+    async fn perform(
+        &self,
+        ctx: &Context,
+        guild_id: &GuildId,
+        user_id: &UserId,
+        reason: &str,
+    ) -> Result<(), SerenityError> {
+        edit_member(ctx, guild_id, user_id, reason, |member| member.deafen(true)).await
+    }
+}
+
+pub struct RedAlertMoveAction {
+    pub channel_id: ChannelId,
+}
+
+#[async_trait]
+impl RedAlertAction for RedAlertMoveAction {
+    async fn perform(
+        &self,
+        ctx: &Context,
+        guild_id: &GuildId,
+        user_id: &UserId,
+        reason: &str,
+    ) -> Result<(), SerenityError> {
+        edit_member(ctx, guild_id, user_id, reason, |member| {
+            member.voice_channel(self.channel_id)
+        })
+        .await
+    }
+}
+
+pub struct RedAlertTimeoutAction {
+    pub minutes: u64,
+}
+
+impl RedAlertTimeoutAction {
+    /// Discord doesn't allow timeouts longer than 28 days.
+    pub const MAX_MINUTES: u64 = 28 * 24 * 60;
+}
+
+#[async_trait]
+impl RedAlertAction for RedAlertTimeoutAction {
+    async fn perform(
+        &self,
+        ctx: &Context,
+        guild_id: &GuildId,
+        user_id: &UserId,
+        reason: &str,
+    ) -> Result<(), SerenityError> {
+        let Some(until) = Some(self.minutes)
+            .filter(|minutes| *minutes <= Self::MAX_MINUTES)
+            .and_then(|minutes| {
+                offset::Utc::now().checked_add_signed(ChronoDuration::minutes(minutes as i64))
+            })
+        else {
+            return Err(SerenityError::Other("Timeout is too long"));
+        };
+        edit_member(ctx, guild_id, user_id, reason, |member| {
+            member.disable_communication_until(until.to_rfc3339())
+        })
+        .await
+    }
+}
+
+/// Gives the role to the user and takes it back after the given minutes.
+pub struct RedAlertTemporaryRoleAction {
+    pub role_id: RoleId,
+    pub minutes: u64,
+}
+
+#[async_trait]
+impl RedAlertAction for RedAlertTemporaryRoleAction {
+    async fn perform(
+        &self,
+        ctx: &Context,
+        guild_id: &GuildId,
+        user_id: &UserId,
+        reason: &str,
+    ) -> Result<(), SerenityError> {
+        let Some(seconds) = self.minutes.checked_mul(60) else {
+            return Err(SerenityError::Other("Temporary role duration is too long"));
+        };
+        ctx.http
+            .add_member_role(guild_id.0, user_id.0, self.role_id.0, Some(reason))
+            .await?;
+        let ctx = ctx.clone();
+        let guild_id = *guild_id;
+        let user_id = *user_id;
+        let role_id = self.role_id;
+        let reason = reason.to_owned();
+        tokio::spawn(async move {
+            tokio::time::sleep(Duration::from_secs(seconds)).await;
+            if let Err(error) = ctx
+                .http
+                .remove_member_role(guild_id.0, user_id.0, role_id.0, Some(&reason))
+                .await
+            {
+                warn!(
+                    "[GID:{}][UID:{}] Temporary role {} removing error: {}.",
+                    guild_id, user_id, role_id, error
+                );
+            }
+        });
+        Ok(())
+    }
+}
+
+#[derive(Clone, Debug, Default, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case", tag = "type")]
+pub enum RedAlertActionKind {
+    Warn,
+    #[default]
+    Disconnect,
+    Mute,
+    Deafen,
+    Move {
+        channel_id: u64,
+    },
+    Timeout {
+        minutes: u64,
+    },
+    TemporaryRole {
+        role_id: u64,
+        minutes: u64,
+    },
+}
+
+impl RedAlertActionKind {
+    pub const NAMES: [&'static str; 7] = [
+        "warn",
+        "disconnect",
+        "mute",
+        "deafen",
+        "move",
+        "timeout",
+        "temporary_role",
+    ];
+    pub fn name(&self) -> &'static str {
+        match self {
+            RedAlertActionKind::Warn => "warn",
+            RedAlertActionKind::Disconnect => "disconnect",
+            RedAlertActionKind::Mute => "mute",
+            RedAlertActionKind::Deafen => "deafen",
+            RedAlertActionKind::Move { .. } => "move",
+            RedAlertActionKind::Timeout { .. } => "timeout",
+            RedAlertActionKind::TemporaryRole { .. } => "temporary_role",
+        }
+    }
+    pub fn action(&self, l10n: &L10n) -> Box<dyn RedAlertAction + Send + Sync> {
+        match self {
+            RedAlertActionKind::Warn => Box::new(RedAlertWarnAction { l10n: l10n.clone() }),
+            RedAlertActionKind::Disconnect => Box::new(RedAlertDisconnectAction),
+            RedAlertActionKind::Mute => Box::new(RedAlertMuteAction),
+            RedAlertActionKind::Deafen => Box::new(RedAlertDeafenAction),
+            RedAlertActionKind::Move { channel_id } => Box::new(RedAlertMoveAction {
+                channel_id: ChannelId(*channel_id),
+            }),
+            RedAlertActionKind::Timeout { minutes } => {
+                Box::new(RedAlertTimeoutAction { minutes: *minutes })
+            }
+            RedAlertActionKind::TemporaryRole { role_id, minutes } => {
+                Box::new(RedAlertTemporaryRoleAction {
+                    role_id: RoleId(*role_id),
+                    minutes: *minutes,
+                })
+            }
+        }
+    }
+    /// Discord rejects only these for a target outside voice channels, other punishments
+    /// would be applied to anyone.
+    pub fn is_voice_move(&self) -> bool {
+        match self {
+            RedAlertActionKind::Disconnect | RedAlertActionKind::Move { .. } => true,
+            RedAlertActionKind::Warn
+            | RedAlertActionKind::Mute
+            | RedAlertActionKind::Deafen
+            | RedAlertActionKind::Timeout { .. }
+            | RedAlertActionKind::TemporaryRole { .. } => false,
+        }
+    }
+    pub fn required_permissions(&self) -> Permissions {
+        match self {
+            RedAlertActionKind::Warn => Permissions::empty(),
+            RedAlertActionKind::Disconnect => Permissions::MOVE_MEMBERS,
+            RedAlertActionKind::Mute => Permissions::MUTE_MEMBERS,
+            RedAlertActionKind::Deafen => Permissions::DEAFEN_MEMBERS,
+            RedAlertActionKind::Move { .. } => Permissions::MOVE_MEMBERS,
+            RedAlertActionKind::Timeout { .. } => Permissions::MODERATE_MEMBERS,
+            RedAlertActionKind::TemporaryRole { .. } => Permissions::MANAGE_ROLES,
+        }
+    }
+    pub fn description(&self, l10n: &L10n) -> String {
+        match self {
+            RedAlertActionKind::Warn => l10n.string("red-alert-action-warn", fluent_args![]),
+            RedAlertActionKind::Disconnect => {
+                l10n.string("red-alert-action-disconnect", fluent_args![])
+            }
+            RedAlertActionKind::Mute => l10n.string("red-alert-action-mute", fluent_args![]),
+            RedAlertActionKind::Deafen => l10n.string("red-alert-action-deafen", fluent_args![]),
+            RedAlertActionKind::Move { channel_id } => l10n.string(
+                "red-alert-action-move",
+                fluent_args![
+                    "channel-name" => ChannelId(*channel_id).mention().to_string()
+                ],
+            ),
+            RedAlertActionKind::Timeout { minutes } => l10n.string(
+                "red-alert-action-timeout",
+                fluent_args![
+                    "minutes" => *minutes
+                ],
+            ),
+            RedAlertActionKind::TemporaryRole { role_id, minutes } => l10n.string(
+                "red-alert-action-temporary-role",
+                fluent_args![
+                    "role-name" => RoleId(*role_id).mention().to_string(),
+                    "minutes" => *minutes
+                ],
+            ),
+        }
+    }
+}
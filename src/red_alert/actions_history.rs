@@ -12,9 +12,44 @@ pub enum RedAlertActionType {
         reason: RedAlertVoiceSearchResult,
         is_success: bool,
     },
+    VoiceSuppressed {
+        author_id: UserId,
+        target_id: UserId,
+        full_text: String,
+        reason: RedAlertVoiceSearchResult,
+    },
+    Vote {
+        author_id: UserId,
+        target_id: UserId,
+        full_text: String,
+        outcome: RedAlertVoteOutcome,
+    },
+    Cancelled {
+        author_id: UserId,
+        target_id: UserId,
+        reason: RedAlertCancelReason,
+    },
+    FailedAttempt {
+        target_id: UserId,
+        action: RedAlertActionKind,
+        attempt: u32,
+        max_attempts: u32,
+        error: String,
+        will_retry: bool,
+    },
+    Immune {
+        author_id: UserId,
+        target_id: UserId,
+    },
+    RateLimited {
+        author_id: UserId,
+        target_id: UserId,
+        limit: RedAlertRateLimit,
+    },
     Text {
         author_id: UserId,
         target_id: UserId,
+        action: RedAlertActionKind,
         is_success: bool,
     },
 }
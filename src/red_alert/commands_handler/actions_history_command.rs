@@ -71,6 +71,7 @@ impl Command for ActionsHistoryRedAlertCommand {
                                             fluent_args![],
                                         )
                                     },
+                                    "action" => reason.action.description(&self.l10n),
                                     "reason-text" => reason_text,
                                     "restricted-word" => reason.word.to_owned(),
                                     "similarity-percent" => (reason.total_similarity * 100.0) as u8
@@ -93,6 +94,7 @@ impl Command for ActionsHistoryRedAlertCommand {
                                         )
                                     },
                                     "author-name" => author_id.mention().to_string(),
+                                    "action" => reason.action.description(&self.l10n),
                                     "reason-text" => reason_text,
                                     "restricted-word" => reason.word.to_owned(),
                                     "similarity-percent" => (reason.total_similarity * 100.0) as u8
@@ -100,9 +102,107 @@ impl Command for ActionsHistoryRedAlertCommand {
                             )
                         }
                     }
+                    RedAlertActionType::VoiceSuppressed {
+                        author_id,
+                        target_id,
+                        full_text,
+                        reason,
+                    } => {
+                        let reason_text = full_text.replace(
+                            reason.real_word.as_str(),
+                            self.l10n
+                                .string(
+                                    "actions-history-red-alert-command-voice-record-reason-format",
+                                    fluent_args![
+                                        "reason" => reason.real_word.to_owned()
+                                    ],
+                                )
+                                .as_str(),
+                        );
+                        self.l10n.string(
+                            "actions-history-red-alert-command-voice-suppressed-record",
+                            fluent_args![
+                                "target-name" => target_id.mention().to_string(),
+                                "author-name" => author_id.mention().to_string(),
+                                "reason-text" => reason_text,
+                                "restricted-word" => reason.word.to_owned(),
+                                "exception" => reason.suppressed_by.clone().unwrap_or_default()
+                            ],
+                        )
+                    }
+                    RedAlertActionType::Vote {
+                        author_id,
+                        target_id,
+                        full_text,
+                        outcome,
+                    } => self.l10n.string(
+                        "actions-history-red-alert-command-vote-record",
+                        fluent_args![
+                            "target-name" => target_id.mention().to_string(),
+                            "author-name" => author_id.mention().to_string(),
+                            "reason-text" => full_text,
+                            "outcome" => outcome.description(&self.l10n)
+                        ],
+                    ),
+                    RedAlertActionType::Cancelled {
+                        author_id,
+                        target_id,
+                        reason,
+                    } => self.l10n.string(
+                        "actions-history-red-alert-command-cancelled-record",
+                        fluent_args![
+                            "target-name" => target_id.mention().to_string(),
+                            "author-name" => author_id.mention().to_string(),
+                            "reason" => reason.description(&self.l10n)
+                        ],
+                    ),
+                    RedAlertActionType::FailedAttempt {
+                        target_id,
+                        action,
+                        attempt,
+                        max_attempts,
+                        error,
+                        will_retry,
+                    } => self.l10n.string(
+                        if *will_retry {
+                            "actions-history-red-alert-command-failed-attempt-retry-record"
+                        } else {
+                            "actions-history-red-alert-command-failed-attempt-record"
+                        },
+                        fluent_args![
+                            "target-name" => target_id.mention().to_string(),
+                            "action" => action.description(&self.l10n),
+                            "attempt" => attempt,
+                            "max-attempts" => max_attempts,
+                            "error" => error
+                        ],
+                    ),
+                    RedAlertActionType::Immune {
+                        author_id,
+                        target_id,
+                    } => self.l10n.string(
+                        "actions-history-red-alert-command-immune-record",
+                        fluent_args![
+                            "target-name" => target_id.mention().to_string(),
+                            "author-name" => author_id.mention().to_string()
+                        ],
+                    ),
+                    RedAlertActionType::RateLimited {
+                        author_id,
+                        target_id,
+                        limit,
+                    } => self.l10n.string(
+                        "actions-history-red-alert-command-rate-limited-record",
+                        fluent_args![
+                            "target-name" => target_id.mention().to_string(),
+                            "author-name" => author_id.mention().to_string(),
+                            "limit" => limit.description(&self.l10n)
+                        ],
+                    ),
                     RedAlertActionType::Text {
                         author_id,
                         target_id,
+                        action,
                         is_success,
                     } => {
                         let target_name = target_id.mention().to_string();
@@ -121,7 +221,8 @@ impl Command for ActionsHistoryRedAlertCommand {
                                             "actions-history-red-alert-command-self-kick-status-fail",
                                             fluent_args![],
                                         )
-                                    }
+                                    },
+                                    "action" => action.description(&self.l10n)
                                 ],
                             )
                         } else {
@@ -140,6 +241,7 @@ impl Command for ActionsHistoryRedAlertCommand {
                                             fluent_args![],
                                         )
                                     },
+                                    "action" => action.description(&self.l10n),
                                     "author-name" => author_id.mention().to_string()
                                 ],
                             )
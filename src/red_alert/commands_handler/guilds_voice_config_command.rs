@@ -1,6 +1,6 @@
 use super::*;
 use serenity::model::prelude::Mention;
-use serenity::model::prelude::UserId;
+use serenity::model::prelude::{ChannelId, RoleId, UserId};
 use serenity::prelude::{Context, Mentionable};
 use std::str::FromStr;
 use std::sync::Arc;
@@ -8,9 +8,34 @@ use tokio::sync::RwLock;
 
 pub(super) struct GuildsVoiceConfigRedAlertCommand {
     pub(super) guilds_voice_config: Arc<RwLock<RedAlertGuildsVoiceConfig>>,
+    pub(super) siren_player: Arc<RedAlertSirenPlayer>,
     pub(super) l10n: L10n,
 }
 
+/// Lowercased args with `/regex/` patterns put back in the original case,
+/// since lowercasing changes the meaning of escapes like `\S` or `\W`.
+fn args_with_patterns_case(args: &[String], raw_args: &[String]) -> Vec<String> {
+    let mut result_args = args.to_vec();
+    let mut index = 0;
+    while index < args.len() {
+        let pattern_end = Some(index)
+            .filter(|index| args[*index].starts_with('/'))
+            .and_then(|index| {
+                (index..args.len()).find(|end| {
+                    args[*end].ends_with('/') && (*end > index || args[*end].len() > 1)
+                })
+            });
+        let Some(pattern_end) = pattern_end else {
+            index += 1;
+            continue;
+        };
+        if let Some(raw_pattern_args) = raw_args.get(index..=pattern_end) {
+            result_args[index..=pattern_end].clone_from_slice(raw_pattern_args);
+        }
+        index = pattern_end + 1;
+    }
+    result_args
+}
 fn process_self_words(
     l10n: &L10n,
     guild_voice_config: &mut RedAlertVoiceConfig<u64>,
@@ -20,7 +45,7 @@ fn process_self_words(
     if let Some(index) = guild_voice_config
         .self_words
         .iter()
-        .position(|w| w == &word)
+        .position(|w| w.phrase.text() == word)
     {
         guild_voice_config.self_words.remove(index);
         l10n.string(
@@ -28,115 +53,1025 @@ fn process_self_words(
             fluent_args![],
         )
     } else {
-        guild_voice_config.self_words.push(word);
+        let word = match SearchPhrase::try_from(word) {
+            Ok(word) => word,
+            Err(error) => {
+                return l10n.string(
+                    "guilds-voice-config-red-alert-command-pattern-error",
+                    fluent_args![
+                        "error" => error.to_string()
+                    ],
+                )
+            }
+        };
+        guild_voice_config
+            .self_words
+            .push(RedAlertVoicePhrase::new(word));
         l10n.string(
             "guilds-voice-config-red-alert-command-self-words-add",
             fluent_args![],
-        )
+        )
+    }
+}
+fn process_target_words(
+    l10n: &L10n,
+    guild_voice_config: &mut RedAlertVoiceConfig<u64>,
+    args: Vec<String>,
+) -> String {
+    let word = args.join(SPACE);
+    if let Some(index) = guild_voice_config
+        .target_words
+        .iter()
+        .position(|w| w.phrase.text() == word)
+    {
+        guild_voice_config.target_words.remove(index);
+        l10n.string(
+            "guilds-voice-config-red-alert-command-target-words-remove",
+            fluent_args![],
+        )
+    } else {
+        let word = match SearchPhrase::try_from(word) {
+            Ok(word) => word,
+            Err(error) => {
+                return l10n.string(
+                    "guilds-voice-config-red-alert-command-pattern-error",
+                    fluent_args![
+                        "error" => error.to_string()
+                    ],
+                )
+            }
+        };
+        guild_voice_config
+            .target_words
+            .push(RedAlertVoicePhrase::new(word));
+        l10n.string(
+            "guilds-voice-config-red-alert-command-target-words-add",
+            fluent_args![],
+        )
+    }
+}
+fn process_exceptions(
+    l10n: &L10n,
+    guild_voice_config: &mut RedAlertVoiceConfig<u64>,
+    args: Vec<String>,
+) -> String {
+    let word = args.join(SPACE);
+    if let Some(index) = guild_voice_config
+        .exceptions
+        .iter()
+        .position(|w| w == &word)
+    {
+        guild_voice_config.exceptions.remove(index);
+        l10n.string(
+            "guilds-voice-config-red-alert-command-exceptions-remove",
+            fluent_args![],
+        )
+    } else {
+        guild_voice_config.exceptions.push(word);
+        l10n.string(
+            "guilds-voice-config-red-alert-command-exceptions-add",
+            fluent_args![],
+        )
+    }
+}
+fn process_aliases(
+    l10n: &L10n,
+    guild_voice_config: &mut RedAlertVoiceConfig<u64>,
+    mut args: Vec<String>,
+) -> String {
+    if !(args.len() > 1) {
+        return l10n.string(
+            "guilds-voice-config-red-alert-command-aliases-empty-params",
+            fluent_args![],
+        );
+    }
+    let target_string = args.remove(args.len() - 1);
+    let Some(target) = (match Mention::from_str(&*target_string) {
+        Ok(mention) => match mention {
+            Mention::User(user_id) => Some(RedAlertVoiceTarget::User(user_id.0)),
+            Mention::Role(role_id) => Some(RedAlertVoiceTarget::Role(role_id.0)),
+            Mention::Channel(_) | Mention::Emoji(_, _) => None,
+        },
+        Err(_) => target_string
+            .parse::<u64>()
+            .ok()
+            .map(RedAlertVoiceTarget::User),
+    }) else {
+        return l10n.string(
+            "guilds-voice-config-red-alert-command-aliases-incorrect-user",
+            fluent_args![],
+        )
+    };
+    let target_mention = match target {
+        RedAlertVoiceTarget::User(user_id) => UserId(user_id).mention().to_string(),
+        RedAlertVoiceTarget::Role(role_id) => RoleId(role_id).mention().to_string(),
+    };
+    let word = args.join(SPACE);
+    let Some(mut saved_alias) = guild_voice_config.aliases.remove(&word) else {
+        guild_voice_config
+            .aliases
+            .insert(word, RedAlertVoiceAlias::new(target));
+        return l10n.string(
+            "guilds-voice-config-red-alert-command-aliases-add",
+            fluent_args![
+                "user-name" => target_mention
+            ],
+        )
+    };
+    let is_added = saved_alias.switch_target(target);
+    if !saved_alias.is_empty() {
+        guild_voice_config.aliases.insert(word, saved_alias);
+    }
+    if is_added {
+        l10n.string(
+            "guilds-voice-config-red-alert-command-aliases-add",
+            fluent_args![
+                "user-name" => target_mention
+            ],
+        )
+    } else {
+        l10n.string(
+            "guilds-voice-config-red-alert-command-aliases-remove",
+            fluent_args![
+                "user-name" => target_mention
+            ],
+        )
+    }
+}
+fn process_immunity(
+    l10n: &L10n,
+    guild_voice_config: &mut RedAlertVoiceConfig<u64>,
+    mut args: Vec<String>,
+) -> String {
+    if !(args.len() > 0) {
+        return l10n.string(
+            "guilds-voice-config-red-alert-command-immunity-empty-params",
+            fluent_args![],
+        );
+    }
+    let target_string = args.remove(0);
+    let Some(target) = (match Mention::from_str(&*target_string) {
+        Ok(mention) => match mention {
+            Mention::User(user_id) => Some(RedAlertVoiceTarget::User(user_id.0)),
+            Mention::Role(role_id) => Some(RedAlertVoiceTarget::Role(role_id.0)),
+            Mention::Channel(_) | Mention::Emoji(_, _) => None,
+        },
+        Err(_) => target_string
+            .parse::<u64>()
+            .ok()
+            .map(RedAlertVoiceTarget::User),
+    }) else {
+        return l10n.string(
+            "guilds-voice-config-red-alert-command-immunity-incorrect-user",
+            fluent_args![],
+        )
+    };
+    let target_mention = match target {
+        RedAlertVoiceTarget::User(user_id) => UserId(user_id).mention().to_string(),
+        RedAlertVoiceTarget::Role(role_id) => RoleId(role_id).mention().to_string(),
+    };
+    if guild_voice_config.immunity.switch_target(target) {
+        l10n.string(
+            "guilds-voice-config-red-alert-command-immunity-add",
+            fluent_args![
+                "user-name" => target_mention
+            ],
+        )
+    } else {
+        l10n.string(
+            "guilds-voice-config-red-alert-command-immunity-remove",
+            fluent_args![
+                "user-name" => target_mention
+            ],
+        )
+    }
+}
+const RESET_OPTION: &str = "-";
+
+fn process_entries_options(
+    l10n: &L10n,
+    guild_voice_config: &mut RedAlertVoiceConfig<u64>,
+    word: &str,
+    update: impl Fn(&mut RedAlertVoiceMatchOptions),
+) -> String {
+    let mut entries_options: Vec<&mut RedAlertVoiceMatchOptions> = vec![];
+    for voice_phrase in guild_voice_config
+        .self_words
+        .iter_mut()
+        .chain(guild_voice_config.target_words.iter_mut())
+    {
+        if voice_phrase.phrase.text() == word {
+            entries_options.push(&mut voice_phrase.options);
+        }
+    }
+    if let Some(alias) = guild_voice_config.aliases.get_mut(word) {
+        entries_options.push(&mut alias.options);
+    }
+    if entries_options.is_empty() {
+        return l10n.string(
+            "guilds-voice-config-red-alert-command-entry-not-found",
+            fluent_args![],
+        );
+    }
+    for entry_options in entries_options {
+        update(entry_options);
+    }
+    l10n.string(
+        "guilds-voice-config-red-alert-command-entry-options-success",
+        fluent_args![
+            "entry" => word.to_owned()
+        ],
+    )
+}
+fn process_similarity_threshold(
+    l10n: &L10n,
+    guild_voice_config: &mut RedAlertVoiceConfig<u64>,
+    mut args: Vec<String>,
+) -> String {
+    if !(args.len() > 0) {
+        return l10n.string(
+            "guilds-voice-config-red-alert-command-similarity-threshold-empty-params",
+            fluent_args![],
+        );
+    }
+    let similarity_threshold_string = args.remove(0);
+    if args.len() > 0 && similarity_threshold_string == RESET_OPTION {
+        let word = args.join(SPACE);
+        return process_entries_options(l10n, guild_voice_config, &word, |options| {
+            options.similarity_threshold = None;
+        });
+    }
+    let Ok(similarity_threshold) = similarity_threshold_string.parse::<f32>() else {
+        return l10n.string(
+            "guilds-voice-config-red-alert-command-similarity-threshold-incorrect-params",
+            fluent_args![],
+        )
+    };
+    let similarity_threshold = similarity_threshold.max(0.0).min(1.0);
+    if args.len() > 0 {
+        let word = args.join(SPACE);
+        return process_entries_options(l10n, guild_voice_config, &word, |options| {
+            options.similarity_threshold = Some(similarity_threshold);
+        });
+    }
+    guild_voice_config.similarity_threshold = similarity_threshold;
+    l10n.string(
+        "guilds-voice-config-red-alert-command-similarity-threshold-success",
+        fluent_args![
+            "similarity-threshold" => similarity_threshold
+        ],
+    )
+}
+fn process_matcher(
+    l10n: &L10n,
+    guild_voice_config: &mut RedAlertVoiceConfig<u64>,
+    mut args: Vec<String>,
+) -> String {
+    if !(args.len() > 0) {
+        return l10n.string(
+            "guilds-voice-config-red-alert-command-matcher-empty-params",
+            fluent_args![],
+        );
+    }
+    let matcher_string = args.remove(0);
+    if args.len() > 0 && matcher_string == RESET_OPTION {
+        let word = args.join(SPACE);
+        return process_entries_options(l10n, guild_voice_config, &word, |options| {
+            options.matcher = None;
+        });
+    }
+    let Some(matcher) = PhraseMatcherKind::from_name(&matcher_string) else {
+        return l10n.string(
+            "guilds-voice-config-red-alert-command-matcher-incorrect-params",
+            fluent_args![
+                "matchers" => PhraseMatcherKind::ALL
+                    .iter()
+                    .map(|kind| kind.name())
+                    .collect::<Vec<&str>>()
+                    .join(", ")
+            ],
+        )
+    };
+    if args.len() > 0 {
+        let word = args.join(SPACE);
+        return process_entries_options(l10n, guild_voice_config, &word, |options| {
+            options.matcher = Some(matcher);
+        });
+    }
+    guild_voice_config.matcher = matcher;
+    l10n.string(
+        "guilds-voice-config-red-alert-command-matcher-success",
+        fluent_args![
+            "matcher" => matcher.name()
+        ],
+    )
+}
+fn process_phonetic_weight(
+    l10n: &L10n,
+    guild_voice_config: &mut RedAlertVoiceConfig<u64>,
+    mut args: Vec<String>,
+) -> String {
+    if !(args.len() > 0) {
+        return l10n.string(
+            "guilds-voice-config-red-alert-command-phonetic-weight-empty-params",
+            fluent_args![],
+        );
+    }
+    let phonetic_weight_string = args.remove(0);
+    if args.len() > 0 && phonetic_weight_string == RESET_OPTION {
+        let word = args.join(SPACE);
+        return process_entries_options(l10n, guild_voice_config, &word, |options| {
+            options.phonetic_weight = None;
+        });
+    }
+    let Ok(phonetic_weight) = phonetic_weight_string.parse::<f32>() else {
+        return l10n.string(
+            "guilds-voice-config-red-alert-command-phonetic-weight-incorrect-params",
+            fluent_args![],
+        )
+    };
+    let phonetic_weight = phonetic_weight.max(0.0).min(1.0);
+    if args.len() > 0 {
+        let word = args.join(SPACE);
+        return process_entries_options(l10n, guild_voice_config, &word, |options| {
+            options.phonetic_weight = Some(phonetic_weight);
+        });
+    }
+    guild_voice_config.phonetic_weight = phonetic_weight;
+    l10n.string(
+        "guilds-voice-config-red-alert-command-phonetic-weight-success",
+        fluent_args![
+            "phonetic-weight" => phonetic_weight
+        ],
+    )
+}
+fn process_near_miss_margin(
+    l10n: &L10n,
+    guild_voice_config: &mut RedAlertVoiceConfig<u64>,
+    mut args: Vec<String>,
+) -> String {
+    if !(args.len() > 0) {
+        return l10n.string(
+            "guilds-voice-config-red-alert-command-near-miss-margin-empty-params",
+            fluent_args![],
+        );
+    }
+    let near_miss_margin_string = args.remove(0);
+    let Ok(near_miss_margin) = near_miss_margin_string.parse::<f32>() else {
+        return l10n.string(
+            "guilds-voice-config-red-alert-command-near-miss-margin-incorrect-params",
+            fluent_args![],
+        )
+    };
+    let near_miss_margin = near_miss_margin.max(0.0).min(1.0);
+    guild_voice_config.near_miss_margin = near_miss_margin;
+    l10n.string(
+        "guilds-voice-config-red-alert-command-near-miss-margin-success",
+        fluent_args![
+            "near-miss-margin" => near_miss_margin
+        ],
+    )
+}
+fn parse_punishment(args: &mut Vec<String>) -> Option<RedAlertActionKind> {
+    if !(args.len() > 0) {
+        return None;
+    }
+    let kind_string = args.remove(0);
+    let parse_minutes = |args: &mut Vec<String>, max_minutes: u64| -> Option<u64> {
+        if !(args.len() > 0) {
+            return None;
+        }
+        args.remove(0)
+            .parse::<u64>()
+            .ok()
+            .filter(|minutes| *minutes > 0 && *minutes <= max_minutes)
+    };
+    match kind_string.as_str() {
+        "warn" => Some(RedAlertActionKind::Warn),
+        "disconnect" => Some(RedAlertActionKind::Disconnect),
+        "mute" => Some(RedAlertActionKind::Mute),
+        "deafen" => Some(RedAlertActionKind::Deafen),
+        "move" => {
+            if !(args.len() > 0) {
+                return None;
+            }
+            let channel_string = args.remove(0);
+            let channel_id = match Mention::from_str(&*channel_string) {
+                Ok(Mention::Channel(channel_id)) => Some(channel_id.0),
+                Ok(_) => None,
+                Err(_) => channel_string.parse::<u64>().ok(),
+            }?;
+            Some(RedAlertActionKind::Move { channel_id })
+        }
+        "timeout" => Some(RedAlertActionKind::Timeout {
+            minutes: parse_minutes(args, RedAlertTimeoutAction::MAX_MINUTES)?,
+        }),
+        "temporary_role" => {
+            if !(args.len() > 0) {
+                return None;
+            }
+            let role_string = args.remove(0);
+            let role_id = match Mention::from_str(&*role_string) {
+                Ok(Mention::Role(role_id)) => Some(role_id.0),
+                Ok(_) => None,
+                Err(_) => role_string.parse::<u64>().ok(),
+            }?;
+            Some(RedAlertActionKind::TemporaryRole {
+                role_id,
+                minutes: parse_minutes(args, u64::MAX / 60)?,
+            })
+        }
+        _ => None,
+    }
+}
+fn process_punishment(
+    l10n: &L10n,
+    guild_voice_config: &mut RedAlertVoiceConfig<u64>,
+    mut args: Vec<String>,
+) -> String {
+    if !(args.len() > 0) {
+        return l10n.string(
+            "guilds-voice-config-red-alert-command-punishment-empty-params",
+            fluent_args![],
+        );
+    }
+    if args.len() > 1 && args[0] == RESET_OPTION {
+        let word = args[1..].join(SPACE);
+        return process_entries_options(l10n, guild_voice_config, &word, |options| {
+            options.action = None;
+        });
+    }
+    let Some(action) = parse_punishment(&mut args) else {
+        return l10n.string(
+            "guilds-voice-config-red-alert-command-punishment-incorrect-params",
+            fluent_args![
+                "actions" => RedAlertActionKind::NAMES.join(", ")
+            ],
+        )
+    };
+    if args.len() > 0 {
+        let word = args.join(SPACE);
+        return process_entries_options(l10n, guild_voice_config, &word, |options| {
+            options.action = Some(action.clone());
+        });
+    }
+    let answer = l10n.string(
+        "guilds-voice-config-red-alert-command-punishment-success",
+        fluent_args![
+            "action" => action.description(l10n)
+        ],
+    );
+    guild_voice_config.action = action;
+    answer
+}
+fn process_escalation(
+    l10n: &L10n,
+    guild_voice_config: &mut RedAlertVoiceConfig<u64>,
+    mut args: Vec<String>,
+) -> String {
+    if !(args.len() > 0) {
+        return l10n.string(
+            "guilds-voice-config-red-alert-command-escalation-empty-params",
+            fluent_args![],
+        );
+    }
+    let window_string = args.remove(0);
+    if window_string == RESET_OPTION {
+        guild_voice_config.escalation = None;
+        return l10n.string(
+            "guilds-voice-config-red-alert-command-escalation-disabled",
+            fluent_args![],
+        );
+    }
+    let Ok(window_minutes) = window_string.parse::<u64>() else {
+        return l10n.string(
+            "guilds-voice-config-red-alert-command-escalation-incorrect-params",
+            fluent_args![
+                "actions" => RedAlertActionKind::NAMES.join(", ")
+            ],
+        )
+    };
+    let mut steps: Vec<RedAlertActionKind> = vec![];
+    while args.len() > 0 {
+        let Some(step) = parse_punishment(&mut args) else {
+            return l10n.string(
+                "guilds-voice-config-red-alert-command-escalation-incorrect-params",
+                fluent_args![
+                    "actions" => RedAlertActionKind::NAMES.join(", ")
+                ],
+            )
+        };
+        steps.push(step);
+    }
+    if steps.is_empty() {
+        return l10n.string(
+            "guilds-voice-config-red-alert-command-escalation-empty-params",
+            fluent_args![],
+        );
+    }
+    let answer = l10n.string(
+        "guilds-voice-config-red-alert-command-escalation-success",
+        fluent_args![
+            "steps" => steps
+                .iter()
+                .map(|step| step.description(l10n))
+                .collect::<Vec<String>>()
+                .join(" → ")
+        ],
+    );
+    guild_voice_config.escalation = Some(RedAlertEscalationPolicy {
+        steps,
+        window_minutes: if window_minutes > 0 {
+            Some(window_minutes)
+        } else {
+            None
+        },
+    });
+    answer
+}
+fn process_cooldown(
+    l10n: &L10n,
+    guild_voice_config: &mut RedAlertVoiceConfig<u64>,
+    mut args: Vec<String>,
+) -> String {
+    if !(args.len() > 1) {
+        return l10n.string(
+            "guilds-voice-config-red-alert-command-cooldown-empty-params",
+            fluent_args![],
+        );
+    }
+    let kind_string = args.remove(0);
+    let Ok(value) = args.remove(0).parse::<u64>() else {
+        return l10n.string(
+            "guilds-voice-config-red-alert-command-cooldown-incorrect-params",
+            fluent_args![],
+        )
+    };
+    if kind_string
+        == l10n.string(
+            "guilds-voice-config-red-alert-command-cooldown-target-kind",
+            fluent_args![],
+        )
+    {
+        guild_voice_config.cooldowns.target_seconds = value;
+        l10n.string(
+            "guilds-voice-config-red-alert-command-cooldown-target-success",
+            fluent_args![
+                "value" => value
+            ],
+        )
+    } else if kind_string
+        == l10n.string(
+            "guilds-voice-config-red-alert-command-cooldown-author-kind",
+            fluent_args![],
+        )
+    {
+        guild_voice_config.cooldowns.author_seconds = value;
+        l10n.string(
+            "guilds-voice-config-red-alert-command-cooldown-author-success",
+            fluent_args![
+                "value" => value
+            ],
+        )
+    } else if kind_string
+        == l10n.string(
+            "guilds-voice-config-red-alert-command-cooldown-guild-kind",
+            fluent_args![],
+        )
+    {
+        guild_voice_config.cooldowns.guild_kicks_per_minute = value as usize;
+        l10n.string(
+            "guilds-voice-config-red-alert-command-cooldown-guild-success",
+            fluent_args![
+                "value" => value
+            ],
+        )
+    } else {
+        l10n.string(
+            "guilds-voice-config-red-alert-command-cooldown-incorrect-params",
+            fluent_args![],
+        )
+    }
+}
+fn process_vote(
+    l10n: &L10n,
+    guild_voice_config: &mut RedAlertVoiceConfig<u64>,
+    mut args: Vec<String>,
+) -> String {
+    if args.first().map_or(false, |arg| arg == RESET_OPTION) {
+        guild_voice_config.vote = None;
+        return l10n.string(
+            "guilds-voice-config-red-alert-command-vote-disabled",
+            fluent_args![],
+        );
+    }
+    if !(args.len() > 2) {
+        return l10n.string(
+            "guilds-voice-config-red-alert-command-vote-empty-params",
+            fluent_args![],
+        );
+    }
+    let channel_string = args.remove(0);
+    let channel_id = match Mention::from_str(&*channel_string) {
+        Ok(Mention::Channel(channel_id)) => Some(channel_id.0),
+        Ok(_) => None,
+        Err(_) => channel_string.parse::<u64>().ok(),
+    };
+    let required_votes = args.remove(0).parse::<usize>().ok().filter(|votes| *votes > 0);
+    let timeout_seconds = args.remove(0).parse::<u64>().ok().filter(|seconds| *seconds > 0);
+    let (Some(channel_id), Some(required_votes), Some(timeout_seconds)) =
+        (channel_id, required_votes, timeout_seconds) else {
+        return l10n.string(
+            "guilds-voice-config-red-alert-command-vote-incorrect-params",
+            fluent_args![],
+        );
+    };
+    let vote_config = RedAlertVoteConfig {
+        channel_id,
+        required_votes,
+        timeout_seconds,
+    };
+    let answer = l10n.string(
+        "guilds-voice-config-red-alert-command-vote-success",
+        fluent_args![
+            "vote" => vote_description(l10n, &vote_config)
+        ],
+    );
+    guild_voice_config.vote = Some(vote_config);
+    answer
+}
+fn vote_description(l10n: &L10n, vote_config: &RedAlertVoteConfig) -> String {
+    l10n.string(
+        "guilds-voice-config-red-alert-command-vote-description",
+        fluent_args![
+            "channel-name" => ChannelId(vote_config.channel_id).mention().to_string(),
+            "votes" => vote_config.required_votes,
+            "seconds" => vote_config.timeout_seconds
+        ],
+    )
+}
+fn process_countdown(
+    l10n: &L10n,
+    guild_voice_config: &mut RedAlertVoiceConfig<u64>,
+    mut args: Vec<String>,
+) -> String {
+    if !(args.len() > 0) {
+        return l10n.string(
+            "guilds-voice-config-red-alert-command-countdown-empty-params",
+            fluent_args![],
+        );
+    }
+    let Some(seconds) = args
+        .remove(0)
+        .parse::<u64>()
+        .ok()
+        .filter(|seconds| *seconds <= RedAlertCountdown::MAX_SECONDS)
+    else {
+        return l10n.string(
+            "guilds-voice-config-red-alert-command-countdown-incorrect-params",
+            fluent_args![
+                "max-seconds" => RedAlertCountdown::MAX_SECONDS
+            ],
+        )
+    };
+    guild_voice_config.countdown.seconds = seconds;
+    if seconds > 0 {
+        l10n.string(
+            "guilds-voice-config-red-alert-command-countdown-success",
+            fluent_args![
+                "seconds" => seconds
+            ],
+        )
+    } else {
+        l10n.string(
+            "guilds-voice-config-red-alert-command-countdown-disabled",
+            fluent_args![],
+        )
+    }
+}
+fn process_cancel_phrases(
+    l10n: &L10n,
+    guild_voice_config: &mut RedAlertVoiceConfig<u64>,
+    args: Vec<String>,
+) -> String {
+    let phrase = args.join(SPACE);
+    if phrase.is_empty() {
+        return l10n.string(
+            "guilds-voice-config-red-alert-command-cancel-phrases-empty-params",
+            fluent_args![],
+        );
+    }
+    let cancel_phrases = &mut guild_voice_config.countdown.cancel_phrases;
+    if let Some(index) = cancel_phrases.iter().position(|p| p == &phrase) {
+        cancel_phrases.remove(index);
+        l10n.string(
+            "guilds-voice-config-red-alert-command-cancel-phrases-remove",
+            fluent_args![],
+        )
+    } else {
+        cancel_phrases.push(phrase);
+        l10n.string(
+            "guilds-voice-config-red-alert-command-cancel-phrases-add",
+            fluent_args![],
+        )
+    }
+}
+fn process_log_channel(
+    l10n: &L10n,
+    guild_voice_config: &mut RedAlertVoiceConfig<u64>,
+    mut args: Vec<String>,
+) -> String {
+    if !(args.len() > 0) {
+        return l10n.string(
+            "guilds-voice-config-red-alert-command-log-channel-empty-params",
+            fluent_args![],
+        );
+    }
+    let channel_string = args.remove(0);
+    if channel_string == RESET_OPTION {
+        guild_voice_config.log_channel_id = None;
+        return l10n.string(
+            "guilds-voice-config-red-alert-command-log-channel-disabled",
+            fluent_args![],
+        );
+    }
+    let Some(channel_id) = (match Mention::from_str(&*channel_string) {
+        Ok(Mention::Channel(channel_id)) => Some(channel_id.0),
+        Ok(_) => None,
+        Err(_) => channel_string.parse::<u64>().ok(),
+    }) else {
+        return l10n.string(
+            "guilds-voice-config-red-alert-command-log-channel-incorrect-params",
+            fluent_args![],
+        )
+    };
+    guild_voice_config.log_channel_id = Some(channel_id);
+    l10n.string(
+        "guilds-voice-config-red-alert-command-log-channel-success",
+        fluent_args![
+            "channel-name" => ChannelId(channel_id).mention().to_string()
+        ],
+    )
+}
+fn process_siren(
+    l10n: &L10n,
+    guild_voice_config: &mut RedAlertVoiceConfig<u64>,
+    siren_player: &RedAlertSirenPlayer,
+    mut args: Vec<String>,
+    raw_args: Vec<String>,
+) -> String {
+    if !(args.len() > 0) {
+        return l10n.string(
+            "guilds-voice-config-red-alert-command-siren-empty-params",
+            fluent_args![],
+        );
+    }
+    let path = args.remove(0);
+    let path = match raw_args.into_iter().next() {
+        Some(raw_path) if path != RESET_OPTION => raw_path,
+        _ => path,
+    };
+    if path != RESET_OPTION {
+        let l10n_key = match siren_player.resolve(&path) {
+            Ok(_) => None,
+            Err(RedAlertSirenError::SirensDisabled) => {
+                Some("guilds-voice-config-red-alert-command-siren-not-configured")
+            }
+            Err(RedAlertSirenError::ForbiddenPath) => {
+                Some("guilds-voice-config-red-alert-command-siren-forbidden-path")
+            }
+            Err(_) => Some("guilds-voice-config-red-alert-command-siren-not-found"),
+        };
+        if let Some(l10n_key) = l10n_key {
+            return l10n.string(
+                l10n_key,
+                fluent_args![
+                    "path" => path
+                ],
+            );
+        }
+    }
+    if args.len() > 0 {
+        let word = args.join(SPACE);
+        let siren = if path == RESET_OPTION { None } else { Some(path) };
+        return process_entries_options(l10n, guild_voice_config, &word, |options| {
+            options.siren = siren.clone();
+        });
+    }
+    if path == RESET_OPTION {
+        guild_voice_config.siren.path = None;
+        return l10n.string(
+            "guilds-voice-config-red-alert-command-siren-disabled",
+            fluent_args![],
+        );
+    }
+    let answer = l10n.string(
+        "guilds-voice-config-red-alert-command-siren-success",
+        fluent_args![
+            "path" => path.clone()
+        ],
+    );
+    guild_voice_config.siren.path = Some(path);
+    answer
+}
+fn process_siren_volume(
+    l10n: &L10n,
+    guild_voice_config: &mut RedAlertVoiceConfig<u64>,
+    mut args: Vec<String>,
+) -> String {
+    if !(args.len() > 0) {
+        return l10n.string(
+            "guilds-voice-config-red-alert-command-siren-volume-empty-params",
+            fluent_args![],
+        );
+    }
+    let Ok(volume) = args.remove(0).parse::<f32>() else {
+        return l10n.string(
+            "guilds-voice-config-red-alert-command-siren-volume-incorrect-params",
+            fluent_args![],
+        )
+    };
+    let volume = volume.max(0.0).min(1.0);
+    guild_voice_config.siren.volume = volume;
+    l10n.string(
+        "guilds-voice-config-red-alert-command-siren-volume-success",
+        fluent_args![
+            "volume" => volume
+        ],
+    )
+}
+fn process_retry(
+    l10n: &L10n,
+    guild_voice_config: &mut RedAlertVoiceConfig<u64>,
+    mut args: Vec<String>,
+) -> String {
+    if !(args.len() > 0) {
+        return l10n.string(
+            "guilds-voice-config-red-alert-command-retry-empty-params",
+            fluent_args![],
+        );
     }
+    let Ok(max_attempts) = args.remove(0).parse::<u32>() else {
+        return l10n.string(
+            "guilds-voice-config-red-alert-command-retry-incorrect-params",
+            fluent_args![],
+        )
+    };
+    let base_delay_ms = match args.first() {
+        Some(base_delay_string) => {
+            let Ok(base_delay_ms) = base_delay_string.parse::<u64>() else {
+                return l10n.string(
+                    "guilds-voice-config-red-alert-command-retry-incorrect-params",
+                    fluent_args![],
+                )
+            };
+            base_delay_ms
+        }
+        None => guild_voice_config.retry.base_delay_ms,
+    };
+    guild_voice_config.retry.max_attempts = max_attempts.max(1);
+    guild_voice_config.retry.base_delay_ms = base_delay_ms;
+    l10n.string(
+        "guilds-voice-config-red-alert-command-retry-success",
+        fluent_args![
+            "attempts" => guild_voice_config.retry.max_attempts,
+            "delay" => base_delay_ms
+        ],
+    )
 }
-fn process_target_words(
+fn process_notification(
     l10n: &L10n,
     guild_voice_config: &mut RedAlertVoiceConfig<u64>,
     args: Vec<String>,
 ) -> String {
-    let word = args.join(SPACE);
-    if let Some(index) = guild_voice_config
-        .target_words
-        .iter()
-        .position(|w| w == &word)
-    {
-        guild_voice_config.target_words.remove(index);
-        l10n.string(
-            "guilds-voice-config-red-alert-command-target-words-remove",
-            fluent_args![],
-        )
-    } else {
-        guild_voice_config.target_words.push(word);
-        l10n.string(
-            "guilds-voice-config-red-alert-command-target-words-add",
+    if !(args.len() > 0) {
+        guild_voice_config.notification.enabled = !guild_voice_config.notification.enabled;
+        return if guild_voice_config.notification.enabled {
+            l10n.string(
+                "guilds-voice-config-red-alert-command-notification-add",
+                fluent_args![],
+            )
+        } else {
+            l10n.string(
+                "guilds-voice-config-red-alert-command-notification-remove",
+                fluent_args![],
+            )
+        };
+    }
+    let appeal = args.join(SPACE);
+    if appeal == RESET_OPTION {
+        guild_voice_config.notification.appeal = None;
+        return l10n.string(
+            "guilds-voice-config-red-alert-command-notification-appeal-reset",
             fluent_args![],
-        )
+        );
     }
+    let answer = l10n.string(
+        "guilds-voice-config-red-alert-command-notification-appeal-success",
+        fluent_args![
+            "appeal" => appeal.clone()
+        ],
+    );
+    guild_voice_config.notification.appeal = Some(appeal);
+    answer
 }
-fn process_aliases(
+fn process_proximity_window(
     l10n: &L10n,
     guild_voice_config: &mut RedAlertVoiceConfig<u64>,
     mut args: Vec<String>,
 ) -> String {
-    if !(args.len() > 1) {
+    if !(args.len() > 0) {
         return l10n.string(
-            "guilds-voice-config-red-alert-command-aliases-empty-params",
+            "guilds-voice-config-red-alert-command-proximity-window-empty-params",
             fluent_args![],
         );
     }
-    let user_id_string = args.remove(args.len() - 1);
-    let Some(user_id) = (match Mention::from_str(&*user_id_string) {
-        Ok(mention) => match mention {
-            Mention::User(user_id) => Some(user_id),
-            Mention::Channel(_) | Mention::Role(_) | Mention::Emoji(_, _) => None,
-        },
-        Err(_) => user_id_string.parse::<u64>().ok().map(UserId),
-    }) else {
+    let proximity_window_string = args.remove(0);
+    let Ok(proximity_window) = proximity_window_string.parse::<usize>() else {
         return l10n.string(
-            "guilds-voice-config-red-alert-command-aliases-incorrect-user",
+            "guilds-voice-config-red-alert-command-proximity-window-incorrect-params",
             fluent_args![],
         )
     };
-    let word = args.join(SPACE);
-    let Some(saved_user_id) = guild_voice_config.aliases.remove(&word) else {
-        guild_voice_config.aliases.insert(word, user_id.0);
-        return l10n.string(
-            "guilds-voice-config-red-alert-command-aliases-add",
-            fluent_args![
-                "user-name" => user_id.mention().to_string()
-            ],
-        )
-    };
-    if saved_user_id == user_id.0 {
+    if proximity_window > 0 {
+        guild_voice_config.proximity_window = Some(proximity_window);
         l10n.string(
-            "guilds-voice-config-red-alert-command-aliases-remove",
+            "guilds-voice-config-red-alert-command-proximity-window-success",
             fluent_args![
-                "user-name" => user_id.mention().to_string()
+                "proximity-window" => proximity_window
             ],
         )
     } else {
-        guild_voice_config.aliases.insert(word, user_id.0);
+        guild_voice_config.proximity_window = None;
         l10n.string(
-            "guilds-voice-config-red-alert-command-aliases-replace",
-            fluent_args![
-                "user-name" => user_id.mention().to_string()
-            ],
+            "guilds-voice-config-red-alert-command-proximity-window-disabled",
+            fluent_args![],
         )
     }
 }
-fn process_similarity_threshold(
+fn process_language(
     l10n: &L10n,
     guild_voice_config: &mut RedAlertVoiceConfig<u64>,
     mut args: Vec<String>,
 ) -> String {
     if !(args.len() > 0) {
         return l10n.string(
-            "guilds-voice-config-red-alert-command-similarity-threshold-empty-params",
+            "guilds-voice-config-red-alert-command-language-empty-params",
             fluent_args![],
         );
     }
-    let similarity_threshold_string = args.remove(0);
-    let Ok(similarity_threshold) = similarity_threshold_string.parse::<f32>() else {
+    let language_string = args.remove(0);
+    let Some(language) = NormalizationLanguage::from_name(&language_string) else {
         return l10n.string(
-            "guilds-voice-config-red-alert-command-similarity-threshold-incorrect-params",
-            fluent_args![],
+            "guilds-voice-config-red-alert-command-language-incorrect-params",
+            fluent_args![
+                "languages" => NormalizationLanguage::ALL
+                    .iter()
+                    .map(|language| language.name())
+                    .collect::<Vec<&str>>()
+                    .join(", ")
+            ],
         )
     };
-    let similarity_threshold = similarity_threshold.max(0.0).min(1.0);
-    guild_voice_config.similarity_threshold = similarity_threshold;
+    guild_voice_config.normalization.language = language;
     l10n.string(
-        "guilds-voice-config-red-alert-command-similarity-threshold-success",
+        "guilds-voice-config-red-alert-command-language-success",
         fluent_args![
-            "similarity-threshold" => similarity_threshold
+            "language" => language.name()
         ],
     )
 }
+fn process_stemming(l10n: &L10n, guild_voice_config: &mut RedAlertVoiceConfig<u64>) -> String {
+    guild_voice_config.normalization.stemming = !guild_voice_config.normalization.stemming;
+    if guild_voice_config.normalization.stemming {
+        l10n.string(
+            "guilds-voice-config-red-alert-command-stemming-add",
+            fluent_args![],
+        )
+    } else {
+        l10n.string(
+            "guilds-voice-config-red-alert-command-stemming-remove",
+            fluent_args![],
+        )
+    }
+}
+fn process_derive_aliases(
+    l10n: &L10n,
+    guild_voice_config: &mut RedAlertVoiceConfig<u64>,
+) -> String {
+    guild_voice_config.derive_aliases = !guild_voice_config.derive_aliases;
+    if guild_voice_config.derive_aliases {
+        l10n.string(
+            "guilds-voice-config-red-alert-command-derive-aliases-add",
+            fluent_args![],
+        )
+    } else {
+        l10n.string(
+            "guilds-voice-config-red-alert-command-derive-aliases-remove",
+            fluent_args![],
+        )
+    }
+}
 fn process_editors(
     l10n: &L10n,
     guild_voice_config: &mut RedAlertVoiceConfig<u64>,
@@ -186,17 +1121,164 @@ fn process_editors(
     guild_voice_config.editors = Some(editors);
     answer
 }
-fn process_list(l10n: &L10n, guild_voice_config: &RedAlertVoiceConfig<u64>) -> String {
+fn entry_options_description(l10n: &L10n, options: &RedAlertVoiceMatchOptions) -> String {
+    let mut options_parts: Vec<String> = vec![];
+    if let Some(similarity_threshold) = options.similarity_threshold {
+        options_parts.push(l10n.string(
+            "guilds-voice-config-red-alert-command-list-option",
+            fluent_args![
+                "option" => l10n.string(
+                    "guilds-voice-config-red-alert-command-similarity-threshold-action",
+                    fluent_args![],
+                ),
+                "value" => similarity_threshold
+            ],
+        ));
+    }
+    if let Some(matcher) = options.matcher {
+        options_parts.push(l10n.string(
+            "guilds-voice-config-red-alert-command-list-option",
+            fluent_args![
+                "option" => l10n.string(
+                    "guilds-voice-config-red-alert-command-matcher-action",
+                    fluent_args![],
+                ),
+                "value" => matcher.name()
+            ],
+        ));
+    }
+    if let Some(phonetic_weight) = options.phonetic_weight {
+        options_parts.push(l10n.string(
+            "guilds-voice-config-red-alert-command-list-option",
+            fluent_args![
+                "option" => l10n.string(
+                    "guilds-voice-config-red-alert-command-phonetic-weight-action",
+                    fluent_args![],
+                ),
+                "value" => phonetic_weight
+            ],
+        ));
+    }
+    if let Some(action) = &options.action {
+        options_parts.push(l10n.string(
+            "guilds-voice-config-red-alert-command-list-option",
+            fluent_args![
+                "option" => l10n.string(
+                    "guilds-voice-config-red-alert-command-punishment-action",
+                    fluent_args![],
+                ),
+                "value" => action.description(l10n)
+            ],
+        ));
+    }
+    if let Some(siren) = &options.siren {
+        options_parts.push(l10n.string(
+            "guilds-voice-config-red-alert-command-list-option",
+            fluent_args![
+                "option" => l10n.string(
+                    "guilds-voice-config-red-alert-command-siren-action",
+                    fluent_args![],
+                ),
+                "value" => siren.clone()
+            ],
+        ));
+    }
+    options_parts.join(", ")
+}
+fn entry_record(l10n: &L10n, record: String, options: &RedAlertVoiceMatchOptions) -> String {
+    if options.is_empty() {
+        record
+    } else {
+        l10n.string(
+            "guilds-voice-config-red-alert-command-list-record-options",
+            fluent_args![
+                "record" => record,
+                "options" => entry_options_description(l10n, options)
+            ],
+        )
+    }
+}
+fn process_list(
+    l10n: &L10n,
+    guild_voice_config: &RedAlertVoiceConfig<u64>,
+    derived_aliases: Option<&HashMap<String, RedAlertVoiceAlias<u64>>>,
+) -> String {
+    let mut users_derived_names: HashMap<u64, Vec<&String>> = HashMap::new();
+    for (name, alias) in derived_aliases.into_iter().flatten() {
+        if guild_voice_config.aliases.contains_key(name) {
+            continue;
+        }
+        for user_id in &alias.users_ids {
+            users_derived_names.entry(*user_id).or_default().push(name);
+        }
+    }
     l10n.string(
         "guilds-voice-config-red-alert-command-list-template",
         fluent_args![
+            "punishment" => guild_voice_config.action.description(l10n),
+            "escalation" => guild_voice_config
+                .escalation
+                .as_ref()
+                .map(|escalation| escalation
+                    .steps
+                    .iter()
+                    .map(|step| step.description(l10n))
+                    .collect::<Vec<String>>()
+                    .join(" → "))
+                .unwrap_or_else(|| RESET_OPTION.to_owned()),
+            "vote" => guild_voice_config
+                .vote
+                .as_ref()
+                .map(|vote_config| vote_description(l10n, vote_config))
+                .unwrap_or_else(|| RESET_OPTION.to_owned()),
+            "countdown" => guild_voice_config.countdown.seconds,
+            "cancel-phrases" => guild_voice_config.countdown.cancel_phrases.join(", "),
+            "log-channel" => guild_voice_config
+                .log_channel_id
+                .map(|channel_id| ChannelId(channel_id).mention().to_string())
+                .unwrap_or_else(|| RESET_OPTION.to_owned()),
+            "siren" => guild_voice_config
+                .siren
+                .path
+                .clone()
+                .unwrap_or_else(|| RESET_OPTION.to_owned()),
+            "siren-volume" => guild_voice_config.siren.volume,
+            "retry-attempts" => guild_voice_config.retry.max_attempts,
+            "retry-delay" => guild_voice_config.retry.base_delay_ms,
+            "notification" => guild_voice_config.notification.enabled.to_string(),
+            "notification-appeal" => guild_voice_config
+                .notification
+                .appeal
+                .clone()
+                .unwrap_or_else(|| RESET_OPTION.to_owned()),
+            "cooldown-target" => guild_voice_config.cooldowns.target_seconds,
+            "cooldown-author" => guild_voice_config.cooldowns.author_seconds,
+            "cooldown-guild" => guild_voice_config.cooldowns.guild_kicks_per_minute,
+            "immunity" => guild_voice_config
+                .immunity
+                .users_ids
+                .iter()
+                .map(|user_id| UserId(*user_id).mention().to_string())
+                .chain(
+                    guild_voice_config
+                        .immunity
+                        .roles_ids
+                        .iter()
+                        .map(|role_id| RoleId(*role_id).mention().to_string()),
+                )
+                .collect::<Vec<String>>()
+                .join(SPACE),
             "self-words" => guild_voice_config
                 .self_words
                 .iter()
                 .map(|record| l10n.string(
                     "guilds-voice-config-red-alert-command-list-record-single",
                     fluent_args![
-                        "record" => record.clone()
+                        "record" => entry_record(
+                            l10n,
+                            record.phrase.text().to_owned(),
+                            &record.options,
+                        )
                     ],
                 ))
                 .collect::<Vec<String>>()
@@ -204,6 +1286,21 @@ fn process_list(l10n: &L10n, guild_voice_config: &RedAlertVoiceConfig<u64>) -> S
             "target-words" => guild_voice_config
                 .target_words
                 .iter()
+                .map(|record| l10n.string(
+                    "guilds-voice-config-red-alert-command-list-record-single",
+                    fluent_args![
+                        "record" => entry_record(
+                            l10n,
+                            record.phrase.text().to_owned(),
+                            &record.options,
+                        )
+                    ],
+                ))
+                .collect::<Vec<String>>()
+                .join(NEW_LINE),
+            "exceptions" => guild_voice_config
+                .exceptions
+                .iter()
                 .map(|record| l10n.string(
                     "guilds-voice-config-red-alert-command-list-record-single",
                     fluent_args![
@@ -218,11 +1315,45 @@ fn process_list(l10n: &L10n, guild_voice_config: &RedAlertVoiceConfig<u64>) -> S
                 .map(|record| l10n.string(
                     "guilds-voice-config-red-alert-command-list-record-double",
                     fluent_args![
-                        "record-start" => record.0.clone(),
-                        "record-end" => UserId(*record.1).mention().to_string()
+                        "record-start" => entry_record(
+                            l10n,
+                            record.0.clone(),
+                            &record.1.options,
+                        ),
+                        "record-end" => record
+                            .1
+                            .targets()
+                            .map(|target| match target {
+                                RedAlertVoiceTarget::User(user_id) => {
+                                    UserId(*user_id).mention().to_string()
+                                }
+                                RedAlertVoiceTarget::Role(role_id) => {
+                                    RoleId(*role_id).mention().to_string()
+                                }
+                            })
+                            .collect::<Vec<String>>()
+                            .join(SPACE)
                     ],
                 ))
                 .collect::<Vec<String>>()
+                .join(NEW_LINE),
+            "derived-aliases" => users_derived_names
+                .into_iter()
+                .map(|(user_id, mut names)| {
+                    names.sort();
+                    l10n.string(
+                        "guilds-voice-config-red-alert-command-list-record-double",
+                        fluent_args![
+                            "record-start" => UserId(user_id).mention().to_string(),
+                            "record-end" => names
+                                .into_iter()
+                                .cloned()
+                                .collect::<Vec<String>>()
+                                .join(", ")
+                        ],
+                    )
+                })
+                .collect::<Vec<String>>()
                 .join(NEW_LINE)
         ],
     )
@@ -231,8 +1362,28 @@ fn process_list(l10n: &L10n, guild_voice_config: &RedAlertVoiceConfig<u64>) -> S
 enum Action {
     SelfWords,
     TargetWords,
+    Exceptions,
     Aliases,
+    Immunity,
     SimilarityThreshold,
+    Matcher,
+    PhoneticWeight,
+    NearMissMargin,
+    Punishment,
+    Escalation,
+    Cooldown,
+    Vote,
+    Countdown,
+    CancelPhrases,
+    LogChannel,
+    Siren,
+    SirenVolume,
+    Retry,
+    Notification,
+    ProximityWindow,
+    Language,
+    Stemming,
+    DeriveAliases,
     Editors,
     List,
 }
@@ -242,17 +1393,42 @@ impl Action {
         &self,
         l10n: &L10n,
         guild_voice_config: &mut RedAlertVoiceConfig<u64>,
+        derived_aliases: Option<&HashMap<String, RedAlertVoiceAlias<u64>>>,
+        siren_player: &RedAlertSirenPlayer,
         args: Vec<String>,
+        raw_args: Vec<String>,
     ) -> String {
         match self {
             Action::SelfWords => process_self_words(l10n, guild_voice_config, args),
             Action::TargetWords => process_target_words(l10n, guild_voice_config, args),
+            Action::Exceptions => process_exceptions(l10n, guild_voice_config, args),
             Action::Aliases => process_aliases(l10n, guild_voice_config, args),
+            Action::Immunity => process_immunity(l10n, guild_voice_config, args),
             Action::SimilarityThreshold => {
                 process_similarity_threshold(l10n, guild_voice_config, args)
             }
+            Action::Matcher => process_matcher(l10n, guild_voice_config, args),
+            Action::PhoneticWeight => process_phonetic_weight(l10n, guild_voice_config, args),
+            Action::NearMissMargin => process_near_miss_margin(l10n, guild_voice_config, args),
+            Action::Punishment => process_punishment(l10n, guild_voice_config, args),
+            Action::Escalation => process_escalation(l10n, guild_voice_config, args),
+            Action::Cooldown => process_cooldown(l10n, guild_voice_config, args),
+            Action::Vote => process_vote(l10n, guild_voice_config, args),
+            Action::Countdown => process_countdown(l10n, guild_voice_config, args),
+            Action::CancelPhrases => process_cancel_phrases(l10n, guild_voice_config, args),
+            Action::LogChannel => process_log_channel(l10n, guild_voice_config, args),
+            Action::Siren => {
+                process_siren(l10n, guild_voice_config, siren_player, args, raw_args)
+            }
+            Action::SirenVolume => process_siren_volume(l10n, guild_voice_config, args),
+            Action::Retry => process_retry(l10n, guild_voice_config, args),
+            Action::Notification => process_notification(l10n, guild_voice_config, args),
+            Action::ProximityWindow => process_proximity_window(l10n, guild_voice_config, args),
+            Action::Language => process_language(l10n, guild_voice_config, args),
+            Action::Stemming => process_stemming(l10n, guild_voice_config),
+            Action::DeriveAliases => process_derive_aliases(l10n, guild_voice_config),
             Action::Editors => process_editors(l10n, guild_voice_config, args),
-            Action::List => process_list(l10n, guild_voice_config),
+            Action::List => process_list(l10n, guild_voice_config, derived_aliases),
         }
     }
 }
@@ -282,8 +1458,11 @@ impl Command for GuildsVoiceConfigRedAlertCommand {
             return;
         };
         let mut guilds_voice_config = self.guilds_voice_config.write().await;
+        let derived_aliases = guilds_voice_config.derived_aliases(&guild_id).cloned();
         let mut guild_voice_config = guilds_voice_config.remove(&guild_id);
-        let mut args = params.args.to_vec();
+        let was_derive_aliases = guild_voice_config.derive_aliases;
+        let mut args = args_with_patterns_case(params.args, params.raw_args);
+        let mut raw_args = params.raw_args.to_vec();
         let answer_msg = {
             let access_granted = guild_voice_config
                 .editors
@@ -315,6 +1494,13 @@ impl Command for GuildsVoiceConfigRedAlertCommand {
                         ),
                         Action::TargetWords,
                     ),
+                    (
+                        self.l10n.string(
+                            "guilds-voice-config-red-alert-command-exceptions-action",
+                            fluent_args![],
+                        ),
+                        Action::Exceptions,
+                    ),
                     (
                         self.l10n.string(
                             "guilds-voice-config-red-alert-command-aliases-action",
@@ -322,6 +1508,13 @@ impl Command for GuildsVoiceConfigRedAlertCommand {
                         ),
                         Action::Aliases,
                     ),
+                    (
+                        self.l10n.string(
+                            "guilds-voice-config-red-alert-command-immunity-action",
+                            fluent_args![],
+                        ),
+                        Action::Immunity,
+                    ),
                     (
                         self.l10n.string(
                             "guilds-voice-config-red-alert-command-similarity-threshold-action",
@@ -329,6 +1522,132 @@ impl Command for GuildsVoiceConfigRedAlertCommand {
                         ),
                         Action::SimilarityThreshold,
                     ),
+                    (
+                        self.l10n.string(
+                            "guilds-voice-config-red-alert-command-matcher-action",
+                            fluent_args![],
+                        ),
+                        Action::Matcher,
+                    ),
+                    (
+                        self.l10n.string(
+                            "guilds-voice-config-red-alert-command-phonetic-weight-action",
+                            fluent_args![],
+                        ),
+                        Action::PhoneticWeight,
+                    ),
+                    (
+                        self.l10n.string(
+                            "guilds-voice-config-red-alert-command-near-miss-margin-action",
+                            fluent_args![],
+                        ),
+                        Action::NearMissMargin,
+                    ),
+                    (
+                        self.l10n.string(
+                            "guilds-voice-config-red-alert-command-punishment-action",
+                            fluent_args![],
+                        ),
+                        Action::Punishment,
+                    ),
+                    (
+                        self.l10n.string(
+                            "guilds-voice-config-red-alert-command-escalation-action",
+                            fluent_args![],
+                        ),
+                        Action::Escalation,
+                    ),
+                    (
+                        self.l10n.string(
+                            "guilds-voice-config-red-alert-command-cooldown-action",
+                            fluent_args![],
+                        ),
+                        Action::Cooldown,
+                    ),
+                    (
+                        self.l10n.string(
+                            "guilds-voice-config-red-alert-command-vote-action",
+                            fluent_args![],
+                        ),
+                        Action::Vote,
+                    ),
+                    (
+                        self.l10n.string(
+                            "guilds-voice-config-red-alert-command-countdown-action",
+                            fluent_args![],
+                        ),
+                        Action::Countdown,
+                    ),
+                    (
+                        self.l10n.string(
+                            "guilds-voice-config-red-alert-command-cancel-phrases-action",
+                            fluent_args![],
+                        ),
+                        Action::CancelPhrases,
+                    ),
+                    (
+                        self.l10n.string(
+                            "guilds-voice-config-red-alert-command-log-channel-action",
+                            fluent_args![],
+                        ),
+                        Action::LogChannel,
+                    ),
+                    (
+                        self.l10n.string(
+                            "guilds-voice-config-red-alert-command-siren-action",
+                            fluent_args![],
+                        ),
+                        Action::Siren,
+                    ),
+                    (
+                        self.l10n.string(
+                            "guilds-voice-config-red-alert-command-siren-volume-action",
+                            fluent_args![],
+                        ),
+                        Action::SirenVolume,
+                    ),
+                    (
+                        self.l10n.string(
+                            "guilds-voice-config-red-alert-command-retry-action",
+                            fluent_args![],
+                        ),
+                        Action::Retry,
+                    ),
+                    (
+                        self.l10n.string(
+                            "guilds-voice-config-red-alert-command-notification-action",
+                            fluent_args![],
+                        ),
+                        Action::Notification,
+                    ),
+                    (
+                        self.l10n.string(
+                            "guilds-voice-config-red-alert-command-proximity-window-action",
+                            fluent_args![],
+                        ),
+                        Action::ProximityWindow,
+                    ),
+                    (
+                        self.l10n.string(
+                            "guilds-voice-config-red-alert-command-language-action",
+                            fluent_args![],
+                        ),
+                        Action::Language,
+                    ),
+                    (
+                        self.l10n.string(
+                            "guilds-voice-config-red-alert-command-stemming-action",
+                            fluent_args![],
+                        ),
+                        Action::Stemming,
+                    ),
+                    (
+                        self.l10n.string(
+                            "guilds-voice-config-red-alert-command-derive-aliases-action",
+                            fluent_args![],
+                        ),
+                        Action::DeriveAliases,
+                    ),
                     (
                         self.l10n.string(
                             "guilds-voice-config-red-alert-command-editors-action",
@@ -345,8 +1664,18 @@ impl Command for GuildsVoiceConfigRedAlertCommand {
                     ),
                 ]);
                 let action_string = args.remove(0);
+                if !raw_args.is_empty() {
+                    raw_args.remove(0);
+                }
                 if let Some(action) = actions.get(&action_string) {
-                    action.process(&self.l10n, &mut guild_voice_config, args)
+                    action.process(
+                        &self.l10n,
+                        &mut guild_voice_config,
+                        derived_aliases.as_ref(),
+                        &self.siren_player,
+                        args,
+                        raw_args,
+                    )
                 } else if self.l10n.string(
                     "guilds-voice-config-red-alert-command-auto-track-action",
                     fluent_args![],
@@ -371,8 +1700,19 @@ impl Command for GuildsVoiceConfigRedAlertCommand {
                 }
             }
         };
+        let is_derive_aliases = guild_voice_config.derive_aliases;
         guilds_voice_config.insert(guild_id, guild_voice_config);
         guilds_voice_config.write();
+        if is_derive_aliases != was_derive_aliases {
+            guilds_voice_config.set_derived_aliases(
+                guild_id,
+                if is_derive_aliases {
+                    derive_aliases(&ctx, &guild_id)
+                } else {
+                    HashMap::new()
+                },
+            );
+        }
         drop(guilds_voice_config);
         let _ = params.channel_id.say(&ctx, answer_msg).await;
     }
@@ -1,7 +1,13 @@
 mod actions_history_command;
 mod guilds_voice_config_command;
 mod help_command_factory;
+mod near_misses_command;
+mod offenses_command;
+mod on_guild_members_update;
 mod on_ready;
+mod on_voice_state_update;
+mod pending_actions_command;
+mod phrase_test_command;
 mod start_listen_command;
 mod stop_listen_command;
 mod text_command;
@@ -9,7 +15,13 @@ mod text_command;
 use actions_history_command::*;
 use guilds_voice_config_command::*;
 use help_command_factory::*;
+use near_misses_command::*;
+use offenses_command::*;
+use on_guild_members_update::*;
 use on_ready::*;
+use on_voice_state_update::*;
+use pending_actions_command::*;
+use phrase_test_command::*;
 use start_listen_command::*;
 use stop_listen_command::*;
 use text_command::*;
@@ -18,6 +30,7 @@ use super::super::components::*;
 use super::*;
 use serenity::model::id::GuildId;
 use std::collections::HashMap;
+use std::path::PathBuf;
 use std::sync::Arc;
 use tokio::sync::{Mutex, RwLock};
 use voskrust::api::Model as VoskModel;
@@ -25,6 +38,7 @@ use voskrust::api::Model as VoskModel;
 pub struct RedAlertCommandsHandlerConstructor {
     pub recognition_model: VoskModel,
     pub red_alert_handler: Arc<RedAlertHandler>,
+    pub sirens_path: Option<PathBuf>,
     pub l10n: L10n,
 }
 
@@ -32,12 +46,24 @@ impl RedAlertCommandsHandlerConstructor {
     pub fn build(self) -> Handler {
         let guilds_voices_receivers: Arc<RwLock<HashMap<GuildId, VoiceReceiver>>> =
             Arc::new(Default::default());
-        let actions_history: Arc<Mutex<RedAlertActionsHistory>> = Arc::new(Default::default());
+        let actions_history = self.red_alert_handler.actions_history.clone();
+        let near_misses_history: Arc<Mutex<RedAlertNearMissesHistory>> =
+            Arc::new(Default::default());
+        let rate_limiter: Arc<Mutex<RedAlertRateLimiter>> = Arc::new(Default::default());
+        let pending_actions: Arc<Mutex<RedAlertPendingActions>> = Arc::new(Default::default());
+        let siren_player = Arc::new(RedAlertSirenPlayer::new(self.sirens_path));
         let guilds_voice_config = Arc::new(RwLock::new(RedAlertGuildsVoiceConfig::read()));
         Handler {
             help_command_factory: Box::new(RedAlertHelpCommandFactory {
                 l10n: self.l10n.clone(),
             }),
+            on_guild_members_update: Box::new(RedAlertOnGuildMembersUpdate {
+                guilds_voice_config: guilds_voice_config.clone(),
+                scheduled_guilds_ids: Arc::new(Default::default()),
+            }),
+            on_voice_state_update: Box::new(RedAlertOnVoiceStateUpdate {
+                red_alert_handler: self.red_alert_handler.clone(),
+            }),
             on_ready: Box::new(RedAlertOnReady {
                 monitoring_performer: RedAlertMonitoringPerformer {
                     guilds_voices_receivers: guilds_voices_receivers.clone(),
@@ -46,16 +72,24 @@ impl RedAlertCommandsHandlerConstructor {
                 recognizer_performer: RedAlertRecognizerPerformer {
                     guilds_voices_receivers: guilds_voices_receivers.clone(),
                     actions_history: actions_history.clone(),
+                    near_misses_history: near_misses_history.clone(),
+                    rate_limiter: rate_limiter.clone(),
+                    pending_actions: pending_actions.clone(),
+                    siren_player: siren_player.clone(),
                     guilds_voice_config: guilds_voice_config.clone(),
                     recognition_model: self.recognition_model,
                     red_alert_handler: self.red_alert_handler.clone(),
+                    l10n: self.l10n.clone(),
                 },
                 cancel_recognizer_sender: Arc::new(Mutex::new(None)),
                 cancel_monitoring_sender: Arc::new(Mutex::new(None)),
+                guilds_voice_config: guilds_voice_config.clone(),
                 l10n: self.l10n.clone(),
             }),
             commands: vec![
                 Box::new(TextRedAlertCommand {
+                    guilds_voice_config: guilds_voice_config.clone(),
+                    rate_limiter: rate_limiter.clone(),
                     red_alert_handler: self.red_alert_handler.clone(),
                     actions_history: actions_history.clone(),
                     l10n: self.l10n.clone(),
@@ -74,6 +108,26 @@ impl RedAlertCommandsHandlerConstructor {
                 }),
                 Box::new(GuildsVoiceConfigRedAlertCommand {
                     guilds_voice_config: guilds_voice_config.clone(),
+                    siren_player: siren_player.clone(),
+                    l10n: self.l10n.clone(),
+                }),
+                Box::new(PhraseTestRedAlertCommand {
+                    guilds_voice_config: guilds_voice_config.clone(),
+                    l10n: self.l10n.clone(),
+                }),
+                Box::new(NearMissesRedAlertCommand {
+                    guilds_voice_config: guilds_voice_config.clone(),
+                    near_misses_history: near_misses_history.clone(),
+                    l10n: self.l10n.clone(),
+                }),
+                Box::new(OffensesRedAlertCommand {
+                    guilds_voice_config: guilds_voice_config.clone(),
+                    red_alert_handler: self.red_alert_handler.clone(),
+                    l10n: self.l10n.clone(),
+                }),
+                Box::new(PendingActionsRedAlertCommand {
+                    guilds_voice_config: guilds_voice_config.clone(),
+                    pending_actions: pending_actions.clone(),
                     l10n: self.l10n.clone(),
                 }),
             ],
@@ -0,0 +1,268 @@
+use super::*;
+use serenity::model::prelude::{RoleId, UserId};
+use serenity::prelude::{Context, Mentionable};
+use std::collections::{HashMap, HashSet};
+use std::sync::Arc;
+use tokio::sync::{Mutex, RwLock};
+
+const MAX_LISTED_NEAR_MISSES: usize = 20;
+
+fn is_near_miss_caught(
+    match_index: &RedAlertVoiceMatchIndex<u64>,
+    near_miss: &RedAlertNearMiss,
+) -> bool {
+    match_index
+        .rules_reports(&near_miss.author_id.0, &near_miss.full_text, 0.0)
+        .iter()
+        .any(|rule_report| rule_report.word == near_miss.word && rule_report.search_result.is_some())
+}
+
+/// Highest guild similarity threshold at which the real matching catches all the near misses,
+/// nothing if some of them can't be caught by the guild threshold alone (per-entry thresholds).
+fn suggested_threshold(
+    guild_voice_config: &RedAlertVoiceConfig<u64>,
+    derived_aliases: Option<&HashMap<String, RedAlertVoiceAlias<u64>>>,
+    near_misses: &[&RedAlertNearMiss],
+) -> Option<(f32, RedAlertVoiceMatchIndex<u64>)> {
+    let match_index = |threshold_percent: u32| -> RedAlertVoiceMatchIndex<u64> {
+        let mut guild_voice_config = guild_voice_config.clone();
+        guild_voice_config.similarity_threshold = (threshold_percent as f32) / 100.0;
+        RedAlertVoiceMatchIndex::new(&guild_voice_config, derived_aliases)
+    };
+    let is_caught = |match_index: &RedAlertVoiceMatchIndex<u64>| -> bool {
+        near_misses
+            .iter()
+            .all(|near_miss| is_near_miss_caught(match_index, near_miss))
+    };
+    let mut caught_percent: u32 = 0;
+    let mut caught_index = match_index(caught_percent);
+    if !is_caught(&caught_index) {
+        return None;
+    }
+    let mut missed_percent = (guild_voice_config.similarity_threshold.max(0.0).min(1.0) * 100.0)
+        .ceil() as u32
+        + 1;
+    while missed_percent - caught_percent > 1 {
+        let middle_percent = (caught_percent + missed_percent) / 2;
+        let middle_index = match_index(middle_percent);
+        if is_caught(&middle_index) {
+            caught_percent = middle_percent;
+            caught_index = middle_index;
+        } else {
+            missed_percent = middle_percent;
+        }
+    }
+    Some(((caught_percent as f32) / 100.0, caught_index))
+}
+
+pub(super) struct NearMissesRedAlertCommand {
+    pub(super) guilds_voice_config: Arc<RwLock<RedAlertGuildsVoiceConfig>>,
+    pub(super) near_misses_history: Arc<Mutex<RedAlertNearMissesHistory>>,
+    pub(super) l10n: L10n,
+}
+
+impl NearMissesRedAlertCommand {
+    fn near_miss_record(&self, number: usize, near_miss: &RedAlertNearMiss) -> String {
+        self.l10n.string(
+            "near-misses-red-alert-command-record",
+            fluent_args![
+                "record-number" => number,
+                "time" => near_miss.time.format(self.l10n.string(
+                    "actions-history-red-alert-command-voice-record-time-format",
+                    fluent_args![],
+                ).as_str()).to_string(),
+                "author-name" => near_miss.author_id.mention().to_string(),
+                "text" => near_miss.full_text.clone(),
+                "rule" => near_miss.word.clone(),
+                "targets" => near_miss
+                    .targets
+                    .iter()
+                    .map(|target| match target {
+                        RedAlertVoiceTarget::User(user_id) => UserId(*user_id).mention().to_string(),
+                        RedAlertVoiceTarget::Role(role_id) => RoleId(*role_id).mention().to_string(),
+                    })
+                    .collect::<Vec<String>>()
+                    .join(SPACE),
+                "similarity-percent" => (near_miss.similarity * 100.0) as u8,
+                "threshold-percent" => (near_miss.similarity_threshold * 100.0) as u8
+            ],
+        )
+    }
+}
+
+#[async_trait]
+impl Command for NearMissesRedAlertCommand {
+    fn prefix_anchor(&self) -> String {
+        self.l10n.string(
+            "near-misses-red-alert-command-prefix-anchor",
+            fluent_args![],
+        )
+    }
+    fn help_info(&self) -> Option<HelpInfo> {
+        Some(HelpInfo {
+            header_suffix: Some(self.l10n.string(
+                "near-misses-red-alert-command-header-suffix",
+                fluent_args![],
+            )),
+            description: self.l10n.string(
+                "near-misses-red-alert-command-help-description",
+                fluent_args![],
+            ),
+        })
+    }
+    async fn process<'a>(&'a self, ctx: Context, params: CommandParams<'a>) {
+        let Some(guild_id) = params.guild_id else {
+            return;
+        };
+        let guilds_voice_config = self.guilds_voice_config.read().await;
+        let guild_voice_config = guilds_voice_config.get(&guild_id).clone();
+        let derived_aliases = guilds_voice_config.derived_aliases(&guild_id).cloned();
+        drop(guilds_voice_config);
+        let access_granted = guild_voice_config
+            .editors
+            .as_ref()
+            .map_or_else(|| true, |e| e.contains(&params.author.id.0));
+        let is_clear = params.args.first()
+            == Some(&self.l10n.string(
+                "near-misses-red-alert-command-clear-action",
+                fluent_args![],
+            ));
+        let near_misses: Option<Vec<RedAlertNearMiss>> = if access_granted && !is_clear {
+            self.near_misses_history
+                .lock()
+                .await
+                .get(&guild_id)
+                .map(|near_misses| near_misses.iter().cloned().collect())
+        } else {
+            None
+        };
+        let answer_msg = if !access_granted {
+            self.l10n.string(
+                "near-misses-red-alert-command-no-access",
+                fluent_args![],
+            )
+        } else if is_clear {
+            self.near_misses_history.lock().await.clear(&guild_id);
+            self.l10n.string(
+                "near-misses-red-alert-command-clear-success",
+                fluent_args![],
+            )
+        } else if let Some(near_misses) = near_misses {
+            let mut selected_numbers: HashSet<usize> = HashSet::new();
+            for arg in params.args {
+                let Some(number) = arg
+                    .parse::<usize>()
+                    .ok()
+                    .filter(|number| (1..=near_misses.len()).contains(number)) else {
+                    continue;
+                };
+                selected_numbers.insert(number);
+            }
+            let near_misses = Arc::new(near_misses);
+            // Every threshold step rebuilds the guild match index, so it's kept off the runtime.
+            let threshold_suggestion = {
+                let near_misses = near_misses.clone();
+                let selected_numbers = selected_numbers.clone();
+                tokio::task::spawn_blocking(move || {
+                    let selected_near_misses: Vec<&RedAlertNearMiss> = near_misses
+                        .iter()
+                        .enumerate()
+                        .filter(|(near_miss_index, _)| {
+                            selected_numbers.is_empty()
+                                || selected_numbers.contains(&(near_miss_index + 1))
+                        })
+                        .map(|(_, near_miss)| near_miss)
+                        .collect();
+                    let (threshold, match_index) = suggested_threshold(
+                        &guild_voice_config,
+                        derived_aliases.as_ref(),
+                        &selected_near_misses,
+                    )?;
+                    let false_positives_indexes: Vec<usize> = if selected_numbers.is_empty() {
+                        vec![]
+                    } else {
+                        near_misses
+                            .iter()
+                            .enumerate()
+                            .filter(|(near_miss_index, near_miss)| {
+                                !selected_numbers.contains(&(near_miss_index + 1))
+                                    && is_near_miss_caught(&match_index, near_miss)
+                            })
+                            .map(|(near_miss_index, _)| near_miss_index)
+                            .collect()
+                    };
+                    Some((threshold, false_positives_indexes))
+                })
+                .await
+                .ok()
+                .flatten()
+            };
+            let mut result_strings = vec![];
+            if selected_numbers.is_empty() {
+                result_strings.push(self.l10n.string(
+                    "near-misses-red-alert-command-list-header",
+                    fluent_args![],
+                ));
+                let skipped_count = near_misses.len().saturating_sub(MAX_LISTED_NEAR_MISSES);
+                for (near_miss_index, near_miss) in near_misses.iter().enumerate().skip(skipped_count)
+                {
+                    result_strings.push(self.near_miss_record(near_miss_index + 1, near_miss));
+                }
+                result_strings.push(match threshold_suggestion {
+                    Some((threshold, _)) => self.l10n.string(
+                        "near-misses-red-alert-command-all-suggestion",
+                        fluent_args![
+                            "threshold" => threshold
+                        ],
+                    ),
+                    None => self.l10n.string(
+                        "near-misses-red-alert-command-unreachable-suggestion",
+                        fluent_args![],
+                    ),
+                });
+            } else {
+                match threshold_suggestion {
+                    Some((threshold, false_positives_indexes)) => {
+                        result_strings.push(self.l10n.string(
+                            "near-misses-red-alert-command-selected-suggestion",
+                            fluent_args![
+                                "threshold" => threshold,
+                                "count" => selected_numbers.len()
+                            ],
+                        ));
+                        if false_positives_indexes.is_empty() {
+                            result_strings.push(self.l10n.string(
+                                "near-misses-red-alert-command-no-false-positives",
+                                fluent_args![],
+                            ));
+                        } else {
+                            result_strings.push(self.l10n.string(
+                                "near-misses-red-alert-command-false-positives-header",
+                                fluent_args![],
+                            ));
+                            result_strings.extend(false_positives_indexes.into_iter().map(
+                                |near_miss_index| {
+                                    self.near_miss_record(
+                                        near_miss_index + 1,
+                                        &near_misses[near_miss_index],
+                                    )
+                                },
+                            ));
+                        }
+                    }
+                    None => result_strings.push(self.l10n.string(
+                        "near-misses-red-alert-command-unreachable-suggestion",
+                        fluent_args![],
+                    )),
+                }
+            }
+            result_strings.join(NEW_LINE)
+        } else {
+            self.l10n.string(
+                "near-misses-red-alert-command-empty-list",
+                fluent_args![],
+            )
+        };
+        say_chunks(&ctx, params.channel_id, &answer_msg).await;
+    }
+}
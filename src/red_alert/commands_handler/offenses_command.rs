@@ -0,0 +1,117 @@
+use super::*;
+use serenity::model::prelude::{Mention, UserId};
+use serenity::prelude::{Context, Mentionable};
+use std::str::FromStr;
+use std::sync::Arc;
+use tokio::sync::RwLock;
+
+pub(super) struct OffensesRedAlertCommand {
+    pub(super) guilds_voice_config: Arc<RwLock<RedAlertGuildsVoiceConfig>>,
+    pub(super) red_alert_handler: Arc<RedAlertHandler>,
+    pub(super) l10n: L10n,
+}
+
+#[async_trait]
+impl Command for OffensesRedAlertCommand {
+    fn prefix_anchor(&self) -> String {
+        self.l10n
+            .string("offenses-red-alert-command-prefix-anchor", fluent_args![])
+    }
+    fn help_info(&self) -> Option<HelpInfo> {
+        Some(HelpInfo {
+            header_suffix: Some(
+                self.l10n
+                    .string("offenses-red-alert-command-header-suffix", fluent_args![]),
+            ),
+            description: self
+                .l10n
+                .string("offenses-red-alert-command-help-description", fluent_args![]),
+        })
+    }
+    async fn process<'a>(&'a self, ctx: Context, params: CommandParams<'a>) {
+        let Some(guild_id) = params.guild_id else {
+            return;
+        };
+        let (access_granted, action, escalation) = {
+            let guilds_voice_config = self.guilds_voice_config.read().await;
+            let guild_voice_config = guilds_voice_config.get(&guild_id);
+            (
+                guild_voice_config
+                    .editors
+                    .as_ref()
+                    .map_or_else(|| true, |e| e.contains(&params.author.id.0)),
+                guild_voice_config.action.clone(),
+                guild_voice_config.escalation.clone(),
+            )
+        };
+        let mut args = params.args.to_vec();
+        let is_clear = args.first()
+            == Some(
+                &self
+                    .l10n
+                    .string("offenses-red-alert-command-clear-action", fluent_args![]),
+            );
+        if is_clear {
+            args.remove(0);
+        }
+        let user_id = args.first().and_then(|arg| match Mention::from_str(arg) {
+            Ok(Mention::User(user_id)) => Some(user_id),
+            Ok(_) => None,
+            Err(_) => arg.parse::<u64>().ok().map(UserId),
+        });
+        let window_minutes = escalation
+            .as_ref()
+            .and_then(|escalation| escalation.window_minutes);
+        let mut offenses = self.red_alert_handler.offenses.lock().await;
+        let answer_msg = if !access_granted {
+            self.l10n
+                .string("offenses-red-alert-command-no-access", fluent_args![])
+        } else if args.len() > 0 && user_id.is_none() {
+            self.l10n.string(
+                "offenses-red-alert-command-incorrect-params",
+                fluent_args![],
+            )
+        } else if is_clear {
+            offenses.reset(&guild_id, user_id.as_ref());
+            offenses.write().await;
+            self.l10n
+                .string("offenses-red-alert-command-clear-success", fluent_args![])
+        } else {
+            let counts = match user_id {
+                Some(user_id) => {
+                    vec![(user_id, offenses.count(&guild_id, &user_id, window_minutes))]
+                }
+                None => offenses.guild_counts(&guild_id, window_minutes),
+            };
+            if counts.is_empty() {
+                self.l10n
+                    .string("offenses-red-alert-command-empty-list", fluent_args![])
+            } else {
+                let mut result_strings = vec![self.l10n.string(
+                    "offenses-red-alert-command-list-header",
+                    fluent_args![],
+                )];
+                for (record_index, (user_id, count)) in counts.into_iter().enumerate() {
+                    let next_action = escalation
+                        .as_ref()
+                        .and_then(|escalation| {
+                            offenses.escalated_action(&guild_id, &user_id, escalation)
+                        })
+                        .unwrap_or_else(|| action.clone());
+                    result_strings.push(self.l10n.string(
+                        "offenses-red-alert-command-record",
+                        fluent_args![
+                            "record-number" => record_index + 1,
+                            "user-name" => user_id.mention().to_string(),
+                            "count" => count,
+                            "next-action" => next_action.description(&self.l10n)
+                        ],
+                    ));
+                }
+                result_strings.join(NEW_LINE)
+            }
+        };
+        drop(offenses);
+        let _ = params.channel_id.say(&ctx, answer_msg).await;
+    }
+}
@@ -0,0 +1,52 @@
+use super::*;
+use serenity::model::id::GuildId;
+use serenity::prelude::Context;
+use std::collections::HashSet;
+use std::sync::Arc;
+use std::time::Duration;
+use tokio::sync::{Mutex, RwLock};
+
+/// Members chunks and updates come in bursts, aliases are derived once per burst.
+const DERIVE_ALIASES_DELAY: Duration = Duration::from_secs(2);
+
+pub(super) struct RedAlertOnGuildMembersUpdate {
+    pub(super) guilds_voice_config: Arc<RwLock<RedAlertGuildsVoiceConfig>>,
+    pub(super) scheduled_guilds_ids: Arc<Mutex<HashSet<GuildId>>>,
+}
+
+#[async_trait]
+impl OnGuildMembersUpdate for RedAlertOnGuildMembersUpdate {
+    async fn process(&self, ctx: Context, guild_id: GuildId) {
+        let is_derive_aliases = self
+            .guilds_voice_config
+            .read()
+            .await
+            .get(&guild_id)
+            .derive_aliases;
+        if !is_derive_aliases {
+            return;
+        }
+        if !self.scheduled_guilds_ids.lock().await.insert(guild_id) {
+            return;
+        }
+        let guilds_voice_config = self.guilds_voice_config.clone();
+        let scheduled_guilds_ids = self.scheduled_guilds_ids.clone();
+        tokio::spawn(async move {
+            tokio::time::sleep(DERIVE_ALIASES_DELAY).await;
+            scheduled_guilds_ids.lock().await.remove(&guild_id);
+            let derived_aliases = derive_aliases(&ctx, &guild_id);
+            if !guilds_voice_config
+                .read()
+                .await
+                .is_derived_aliases_changed(&guild_id, &derived_aliases)
+            {
+                return;
+            }
+            let mut guilds_voice_config = guilds_voice_config.write().await;
+            if !guilds_voice_config.get(&guild_id).derive_aliases {
+                return;
+            }
+            guilds_voice_config.set_derived_aliases(guild_id, derived_aliases);
+        });
+    }
+}
@@ -1,16 +1,18 @@
 use super::*;
 use serenity::model::gateway::Activity;
+use serenity::model::id::GuildId;
 use serenity::model::prelude::{OnlineStatus, Ready};
 use serenity::prelude::Context;
 use std::sync::Arc;
 use tokio::sync::oneshot::Sender;
-use tokio::sync::Mutex;
+use tokio::sync::{Mutex, RwLock};
 
 pub(super) struct RedAlertOnReady {
     pub(super) monitoring_performer: RedAlertMonitoringPerformer,
     pub(super) recognizer_performer: RedAlertRecognizerPerformer,
     pub(super) cancel_recognizer_sender: Arc<Mutex<Option<Sender<()>>>>,
     pub(super) cancel_monitoring_sender: Arc<Mutex<Option<Sender<()>>>>,
+    pub(super) guilds_voice_config: Arc<RwLock<RedAlertGuildsVoiceConfig>>,
     pub(super) l10n: L10n,
 }
 
@@ -36,4 +38,21 @@ impl OnReady for RedAlertOnReady {
         *cancel_recognizer_sender = Some(new_cancel_recognizer_sender);
         drop(cancel_recognizer_sender);
     }
+    async fn process_cache_ready(&self, ctx: Context, guilds_ids: Vec<GuildId>) {
+        for guild_id in guilds_ids {
+            let required_permissions = {
+                let guilds_voice_config = self.guilds_voice_config.read().await;
+                guild_required_permissions(guilds_voice_config.get(&guild_id))
+            };
+            if let Err(error) =
+                preflight_permissions(&ctx, &guild_id, None, required_permissions).await
+            {
+                warn!(
+                    "[GID:{}] Permissions preflight FAILED: {}.",
+                    guild_id,
+                    preflight_error_description(&self.l10n, &error)
+                );
+            }
+        }
+    }
 }
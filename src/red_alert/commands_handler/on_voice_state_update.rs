@@ -0,0 +1,32 @@
+use super::*;
+use serenity::model::prelude::{GuildId, UserId, VoiceState};
+use serenity::prelude::Context;
+use std::collections::HashMap;
+use std::sync::Arc;
+
+pub(super) struct RedAlertOnVoiceStateUpdate {
+    pub(super) red_alert_handler: Arc<RedAlertHandler>,
+}
+
+#[async_trait]
+impl OnVoiceStateUpdate for RedAlertOnVoiceStateUpdate {
+    async fn process(&self, _ctx: Context, voice_state: VoiceState) {
+        self.red_alert_handler
+            .voice_states
+            .write()
+            .await
+            .update(&voice_state);
+    }
+    async fn process_guild_create(
+        &self,
+        _ctx: Context,
+        guild_id: GuildId,
+        voice_states: &HashMap<UserId, VoiceState>,
+    ) {
+        self.red_alert_handler
+            .voice_states
+            .write()
+            .await
+            .seed(guild_id, voice_states);
+    }
+}
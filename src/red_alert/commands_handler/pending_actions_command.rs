@@ -0,0 +1,111 @@
+use super::*;
+use serenity::model::prelude::{Mention, UserId};
+use serenity::prelude::{Context, Mentionable};
+use std::str::FromStr;
+use std::sync::Arc;
+use std::time::Instant;
+use tokio::sync::{Mutex, RwLock};
+
+pub(super) struct PendingActionsRedAlertCommand {
+    pub(super) guilds_voice_config: Arc<RwLock<RedAlertGuildsVoiceConfig>>,
+    pub(super) pending_actions: Arc<Mutex<RedAlertPendingActions>>,
+    pub(super) l10n: L10n,
+}
+
+#[async_trait]
+impl Command for PendingActionsRedAlertCommand {
+    fn prefix_anchor(&self) -> String {
+        self.l10n.string(
+            "pending-actions-red-alert-command-prefix-anchor",
+            fluent_args![],
+        )
+    }
+    fn help_info(&self) -> Option<HelpInfo> {
+        Some(HelpInfo {
+            header_suffix: Some(self.l10n.string(
+                "pending-actions-red-alert-command-header-suffix",
+                fluent_args![],
+            )),
+            description: self.l10n.string(
+                "pending-actions-red-alert-command-help-description",
+                fluent_args![],
+            ),
+        })
+    }
+    async fn process<'a>(&'a self, ctx: Context, params: CommandParams<'a>) {
+        let Some(guild_id) = params.guild_id else {
+            return;
+        };
+        let access_granted = self
+            .guilds_voice_config
+            .read()
+            .await
+            .get(&guild_id)
+            .editors
+            .as_ref()
+            .map_or_else(|| true, |e| e.contains(&params.author.id.0));
+        let target_id = params
+            .args
+            .first()
+            .and_then(|arg| match Mention::from_str(arg) {
+                Ok(Mention::User(user_id)) => Some(user_id),
+                Ok(_) => None,
+                Err(_) => arg.parse::<u64>().ok().map(UserId),
+            });
+        let mut pending_actions = self.pending_actions.lock().await;
+        let answer_msg = if !access_granted {
+            self.l10n.string(
+                "pending-actions-red-alert-command-no-access",
+                fluent_args![],
+            )
+        } else if let Some(target_id) = target_id {
+            let reason = RedAlertCancelReason::Command(params.author.id);
+            if pending_actions.cancel(&guild_id, &target_id, reason) {
+                self.l10n.string(
+                    "pending-actions-red-alert-command-cancel-success",
+                    fluent_args![
+                        "user-name" => target_id.mention().to_string()
+                    ],
+                )
+            } else {
+                self.l10n.string(
+                    "pending-actions-red-alert-command-not-found",
+                    fluent_args![
+                        "user-name" => target_id.mention().to_string()
+                    ],
+                )
+            }
+        } else {
+            let guild_pending_actions = pending_actions.guild_pending(&guild_id);
+            if guild_pending_actions.is_empty() {
+                self.l10n.string(
+                    "pending-actions-red-alert-command-empty-list",
+                    fluent_args![],
+                )
+            } else {
+                let now = Instant::now();
+                let mut result_strings = vec![self.l10n.string(
+                    "pending-actions-red-alert-command-list-header",
+                    fluent_args![],
+                )];
+                for (record_index, (target_id, pending_action)) in
+                    guild_pending_actions.into_iter().enumerate()
+                {
+                    result_strings.push(self.l10n.string(
+                        "pending-actions-red-alert-command-record",
+                        fluent_args![
+                            "record-number" => record_index + 1,
+                            "target-name" => target_id.mention().to_string(),
+                            "author-name" => pending_action.author_id.mention().to_string(),
+                            "action" => pending_action.action.description(&self.l10n),
+                            "seconds" => pending_action.deadline.saturating_duration_since(now).as_secs()
+                        ],
+                    ));
+                }
+                result_strings.join(NEW_LINE)
+            }
+        };
+        drop(pending_actions);
+        let _ = params.channel_id.say(&ctx, answer_msg).await;
+    }
+}
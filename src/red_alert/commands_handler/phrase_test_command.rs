@@ -0,0 +1,218 @@
+use super::*;
+use serenity::model::prelude::{Mention, RoleId, UserId};
+use serenity::prelude::{Context, Mentionable};
+use std::cmp::Ordering;
+use std::str::FromStr;
+use std::sync::Arc;
+use tokio::sync::RwLock;
+
+const NEAR_MISS_MARGIN: f32 = 0.15;
+const MAX_REPORTED_RULES: usize = 10;
+
+pub(super) struct PhraseTestRedAlertCommand {
+    pub(super) guilds_voice_config: Arc<RwLock<RedAlertGuildsVoiceConfig>>,
+    pub(super) l10n: L10n,
+}
+
+impl PhraseTestRedAlertCommand {
+    fn check_status(&self, is_passed: bool) -> String {
+        if is_passed {
+            self.l10n.string(
+                "phrase-test-red-alert-command-check-passed",
+                fluent_args![],
+            )
+        } else {
+            self.l10n.string(
+                "phrase-test-red-alert-command-check-failed",
+                fluent_args![],
+            )
+        }
+    }
+    fn phrase_report_records(&self, phrase_report: &PhraseMatchReport) -> Vec<String> {
+        if phrase_report.words.is_empty() {
+            return vec![self.l10n.string(
+                "phrase-test-red-alert-command-pattern-record",
+                fluent_args![
+                    "status" => self.check_status(phrase_report.phrase_match.is_some())
+                ],
+            )];
+        }
+        let mut records = vec![self.l10n.string(
+            "phrase-test-red-alert-command-phrase-record",
+            fluent_args![
+                "phrase" => phrase_report
+                    .words
+                    .iter()
+                    .map(|word_report| word_report.query_word.clone())
+                    .collect::<Vec<String>>()
+                    .join(SPACE),
+                "similarity-percent" => (phrase_report.similarity * 100.0) as u8,
+                "threshold-percent" => (phrase_report.similarity_threshold * 100.0) as u8,
+                "status" => self.check_status(phrase_report.phrase_match.is_some())
+            ],
+        )];
+        for word_report in &phrase_report.words {
+            let Some(text_word) = &word_report.text_word else {
+                records.push(self.l10n.string(
+                    "phrase-test-red-alert-command-word-missing",
+                    fluent_args![
+                        "query-word" => word_report.query_word.clone()
+                    ],
+                ));
+                continue;
+            };
+            records.push(self.l10n.string(
+                "phrase-test-red-alert-command-word-record",
+                fluent_args![
+                    "query-word" => word_report.query_word.clone(),
+                    "text-word" => text_word.clone(),
+                    "similarity-percent" => (word_report.similarity * 100.0) as u8,
+                    "threshold-percent" => (word_report.similarity_threshold * 100.0) as u8,
+                    "similarity-status" => self.check_status(word_report.is_similarity_passed()),
+                    "letters-difference" => word_report.letters_count_difference,
+                    "letters-threshold" => word_report.letters_count_threshold,
+                    "letters-status" => self.check_status(word_report.is_letters_count_passed())
+                ],
+            ));
+        }
+        records
+    }
+}
+
+#[async_trait]
+impl Command for PhraseTestRedAlertCommand {
+    fn prefix_anchor(&self) -> String {
+        self.l10n.string(
+            "phrase-test-red-alert-command-prefix-anchor",
+            fluent_args![],
+        )
+    }
+    fn help_info(&self) -> Option<HelpInfo> {
+        Some(HelpInfo {
+            header_suffix: Some(self.l10n.string(
+                "phrase-test-red-alert-command-header-suffix",
+                fluent_args![],
+            )),
+            description: self.l10n.string(
+                "phrase-test-red-alert-command-help-description",
+                fluent_args![],
+            ),
+        })
+    }
+    async fn process<'a>(&'a self, ctx: Context, params: CommandParams<'a>) {
+        let Some(guild_id) = params.guild_id else {
+            return;
+        };
+        let match_index = self
+            .guilds_voice_config
+            .read()
+            .await
+            .match_index(&guild_id);
+        let mut args = params.args.to_vec();
+        let speaker_user_id = match args.first().map(|arg| Mention::from_str(arg)) {
+            Some(Ok(Mention::User(user_id))) => {
+                args.remove(0);
+                user_id
+            }
+            _ => params.author.id,
+        };
+        let text = args.join(SPACE);
+        let access_granted = match_index
+            .config()
+            .editors
+            .as_ref()
+            .map_or_else(|| true, |e| e.contains(&params.author.id.0));
+        let answer_msg = if !access_granted {
+            self.l10n.string(
+                "phrase-test-red-alert-command-no-access",
+                fluent_args![],
+            )
+        } else if text.trim().is_empty() {
+            self.l10n.string(
+                "phrase-test-red-alert-command-empty-phrase",
+                fluent_args![],
+            )
+        } else {
+            let mut rules_reports =
+                match_index.rules_reports(&speaker_user_id.0, &text, NEAR_MISS_MARGIN);
+            rules_reports.sort_by(|a, b| {
+                let ordering = b
+                    .search_result
+                    .is_some()
+                    .cmp(&a.search_result.is_some());
+                if ordering != Ordering::Equal {
+                    return ordering;
+                }
+                b.similarity()
+                    .partial_cmp(&a.similarity())
+                    .unwrap_or(Ordering::Equal)
+            });
+            if rules_reports.is_empty() {
+                self.l10n.string(
+                    "phrase-test-red-alert-command-no-matches",
+                    fluent_args![],
+                )
+            } else {
+                let mut result_strings = vec![self.l10n.string(
+                    "phrase-test-red-alert-command-header",
+                    fluent_args![
+                        "text" => text.clone(),
+                        "user-name" => speaker_user_id.mention().to_string()
+                    ],
+                )];
+                for (rule_index, rule_report) in rules_reports
+                    .iter()
+                    .take(MAX_REPORTED_RULES)
+                    .enumerate()
+                {
+                    let status = match &rule_report.search_result {
+                        Some(search_result) => match &search_result.suppressed_by {
+                            Some(exception) => self.l10n.string(
+                                "phrase-test-red-alert-command-status-suppressed",
+                                fluent_args![
+                                    "exception" => exception.clone()
+                                ],
+                            ),
+                            None => self.l10n.string(
+                                "phrase-test-red-alert-command-status-matched",
+                                fluent_args![
+                                    "similarity-percent" => (search_result.total_similarity * 100.0) as u8
+                                ],
+                            ),
+                        },
+                        None => self.l10n.string(
+                            "phrase-test-red-alert-command-status-near-miss",
+                            fluent_args![],
+                        ),
+                    };
+                    result_strings.push(self.l10n.string(
+                        "phrase-test-red-alert-command-rule-record",
+                        fluent_args![
+                            "record-number" => rule_index + 1,
+                            "rule" => rule_report.word.clone(),
+                            "targets" => rule_report
+                                .targets
+                                .iter()
+                                .map(|target| match target {
+                                    RedAlertVoiceTarget::User(user_id) => {
+                                        UserId(**user_id).mention().to_string()
+                                    }
+                                    RedAlertVoiceTarget::Role(role_id) => {
+                                        RoleId(**role_id).mention().to_string()
+                                    }
+                                })
+                                .collect::<Vec<String>>()
+                                .join(SPACE),
+                            "status" => status
+                        ],
+                    ));
+                    for phrase_report in &rule_report.phrases_reports {
+                        result_strings.extend(self.phrase_report_records(phrase_report));
+                    }
+                }
+                result_strings.join(NEW_LINE)
+            }
+        };
+        say_chunks(&ctx, params.channel_id, &answer_msg).await;
+    }
+}
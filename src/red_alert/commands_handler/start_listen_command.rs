@@ -77,6 +77,13 @@ impl Command for StartListenRedAlertCommand {
                             "channel-name" => channel_name
                         ],
                     ),
+                    StartListenError::Preflight(error) => self.l10n.string(
+                        "start-listen-red-alert-command-preflight-error",
+                        fluent_args![
+                            "channel-name" => channel_name,
+                            "reason" => preflight_error_description(&self.l10n, &error)
+                        ],
+                    ),
                 },
             }
         } else {
@@ -5,9 +5,11 @@ use serenity::model::prelude::UserId;
 use serenity::prelude::{Context, Mentionable, SerenityError};
 use std::str::FromStr;
 use std::sync::Arc;
-use tokio::sync::Mutex;
+use tokio::sync::{Mutex, RwLock};
 
 pub(super) struct TextRedAlertCommand {
+    pub(super) guilds_voice_config: Arc<RwLock<RedAlertGuildsVoiceConfig>>,
+    pub(super) rate_limiter: Arc<Mutex<RedAlertRateLimiter>>,
     pub(super) actions_history: Arc<Mutex<RedAlertActionsHistory>>,
     pub(super) red_alert_handler: Arc<RedAlertHandler>,
     pub(super) l10n: L10n,
@@ -19,10 +21,14 @@ enum CommonRedAlertResult {
     },
     SingleSuccess {
         is_self_kick: bool,
+        action: RedAlertActionKind,
     },
     SingleNotFound {
         auto_self_kick_result: Option<RedAlertDeportationResult>,
     },
+    SingleImmune {
+        is_self_kick: bool,
+    },
     SingleError {
         error: SerenityError,
         is_self_kick_try: bool,
@@ -33,39 +39,108 @@ enum CommonRedAlertResult {
     },
 }
 
+impl CommonRedAlertResult {
+    /// Explicit targets that were actually punished, auto self kicks are not rate limited.
+    fn deported_users_ids(&self, target_users_ids: &[UserId]) -> Vec<UserId> {
+        match self {
+            CommonRedAlertResult::SingleSuccess { .. } => vec![target_users_ids[0]],
+            CommonRedAlertResult::Mass { results, .. } => target_users_ids
+                .iter()
+                .zip(results)
+                .filter(|(_, result)| result.is_deported())
+                .map(|(user_id, _)| *user_id)
+                .collect(),
+            _ => vec![],
+        }
+    }
+}
+
+fn history_record(
+    author_id: UserId,
+    target_id: UserId,
+    action: &RedAlertActionKind,
+    result: &RedAlertDeportationResult,
+) -> RedAlertActionType {
+    if result.is_immune() {
+        RedAlertActionType::Immune {
+            author_id,
+            target_id,
+        }
+    } else {
+        RedAlertActionType::Text {
+            author_id,
+            target_id,
+            action: result.action().cloned().unwrap_or_else(|| action.clone()),
+            is_success: result.is_deported(),
+        }
+    }
+}
+
 async fn common_red_alert(
     red_alert_handler: Arc<RedAlertHandler>,
     ctx: &Context,
     guild_id: &GuildId,
     author_user_id: &UserId,
     target_users_ids: &Vec<UserId>,
+    action: &RedAlertActionKind,
+    guild_voice_config: &RedAlertVoiceConfig<u64>,
 ) -> CommonRedAlertResult {
+    let trigger = RedAlertTrigger::Text {
+        author_id: *author_user_id,
+    };
     let mut red_alert_results = red_alert_handler
-        .multiple(ctx, guild_id, target_users_ids)
+        .multiple(
+            ctx,
+            guild_id,
+            target_users_ids,
+            action,
+            &trigger,
+            guild_voice_config,
+        )
         .await;
     match red_alert_results.len() {
         0 => CommonRedAlertResult::Empty {
             auto_self_kick_result: red_alert_handler
-                .single(ctx, guild_id, author_user_id)
+                .single(
+                    ctx,
+                    guild_id,
+                    author_user_id,
+                    action,
+                    &trigger,
+                    guild_voice_config,
+                )
                 .await,
         },
         1 => {
             let is_self_kick = *author_user_id == target_users_ids[0];
             match red_alert_results.remove(0) {
-                RedAlertDeportationResult::Deported => {
-                    CommonRedAlertResult::SingleSuccess { is_self_kick }
+                RedAlertDeportationResult::Deported(action) => {
+                    CommonRedAlertResult::SingleSuccess {
+                        is_self_kick,
+                        action,
+                    }
                 }
                 RedAlertDeportationResult::NotFound => CommonRedAlertResult::SingleNotFound {
                     auto_self_kick_result: if !is_self_kick {
                         Some(
                             red_alert_handler
-                                .single(ctx, guild_id, author_user_id)
+                                .single(
+                                    ctx,
+                                    guild_id,
+                                    author_user_id,
+                                    action,
+                                    &trigger,
+                                    guild_voice_config,
+                                )
                                 .await,
                         )
                     } else {
                         None
                     },
                 },
+                RedAlertDeportationResult::Immune => {
+                    CommonRedAlertResult::SingleImmune { is_self_kick }
+                }
                 RedAlertDeportationResult::Error(error) => CommonRedAlertResult::SingleError {
                     error,
                     is_self_kick_try: is_self_kick,
@@ -87,7 +162,14 @@ async fn common_red_alert(
                 {
                     Some(
                         red_alert_handler
-                            .single(ctx, guild_id, author_user_id)
+                            .single(
+                                ctx,
+                                guild_id,
+                                author_user_id,
+                                action,
+                                &trigger,
+                                guild_voice_config,
+                            )
                             .await,
                     )
                 } else {
@@ -131,47 +213,135 @@ impl Command for TextRedAlertCommand {
                 Err(_) => a.parse::<u64>().ok().map(UserId),
             })
             .collect();
-        let answer_msg = match common_red_alert(
+        let guild_voice_config = self
+            .guilds_voice_config
+            .read()
+            .await
+            .get(&guild_id)
+            .clone();
+        let action = &guild_voice_config.action;
+        let cooldowns = &guild_voice_config.cooldowns;
+        let mut rate_limiter = self.rate_limiter.lock().await;
+        let mut rate_limits: Vec<(UserId, RedAlertRateLimit)> = vec![];
+        let mut rate_limit_slots: Vec<RedAlertTargetSlot> = vec![];
+        let author_rate_limit = if target_users_ids.iter().any(|user_id| *user_id != author_id) {
+            rate_limiter
+                .acquire_author(guild_id, author_id, cooldowns)
+                .map(Some)
+        } else {
+            Ok(None)
+        };
+        let target_users_ids: Vec<UserId> = target_users_ids
+            .into_iter()
+            .filter(|user_id| {
+                let author_slot = match &author_rate_limit {
+                    Ok(author_slot) => author_slot.as_ref().filter(|_| *user_id != author_id),
+                    Err(limit) if *user_id != author_id => {
+                        rate_limits.push((*user_id, *limit));
+                        return false;
+                    }
+                    Err(_) => None,
+                };
+                match rate_limiter.acquire_target(guild_id, *user_id, author_slot, cooldowns) {
+                    Ok(rate_limit_slot) => {
+                        rate_limit_slots.push(rate_limit_slot);
+                        true
+                    }
+                    Err(limit) => {
+                        rate_limits.push((*user_id, limit));
+                        false
+                    }
+                }
+            })
+            .collect();
+        if let Ok(Some(author_slot)) = author_rate_limit {
+            rate_limiter.finish_author(author_slot);
+        }
+        drop(rate_limiter);
+        if !rate_limits.is_empty() {
+            let mut actions_history = self.actions_history.lock().await;
+            for (target_id, limit) in &rate_limits {
+                actions_history.log_history(
+                    guild_id,
+                    RedAlertActionType::RateLimited {
+                        author_id,
+                        target_id: *target_id,
+                        limit: *limit,
+                    },
+                );
+            }
+        }
+        let rate_limits_strings: Vec<String> = rate_limits
+            .iter()
+            .map(|(user_id, limit)| {
+                self.l10n.string(
+                    "red-alert-command-rate-limited-record",
+                    fluent_args![
+                        "user-name" => user_id.mention().to_string(),
+                        "limit" => limit.description(&self.l10n)
+                    ],
+                )
+            })
+            .collect();
+        if target_users_ids.is_empty() && !rate_limits_strings.is_empty() {
+            let _ = params
+                .channel_id
+                .say(&ctx, rate_limits_strings.join(NEW_LINE))
+                .await;
+            return;
+        }
+        let red_alert_result = common_red_alert(
             self.red_alert_handler.clone(),
             &ctx,
             &guild_id,
             &author_id,
             &target_users_ids,
+            action,
+            &guild_voice_config,
         )
-        .await
-        {
+        .await;
+        let deported_users_ids = red_alert_result.deported_users_ids(&target_users_ids);
+        let mut rate_limiter = self.rate_limiter.lock().await;
+        for (user_id, rate_limit_slot) in target_users_ids.iter().zip(rate_limit_slots) {
+            rate_limiter.finish_target(rate_limit_slot, deported_users_ids.contains(user_id));
+        }
+        drop(rate_limiter);
+        let answer_msg = match red_alert_result {
             CommonRedAlertResult::Empty {
                 auto_self_kick_result,
             } => {
                 self.actions_history.lock().await.log_history(
                     guild_id,
-                    RedAlertActionType::Text {
-                        author_id,
-                        target_id: author_id,
-                        is_success: auto_self_kick_result.is_deported(),
-                    },
+                    history_record(author_id, author_id, action, &auto_self_kick_result),
                 );
                 match auto_self_kick_result {
-                    RedAlertDeportationResult::Deported => self
+                    RedAlertDeportationResult::Deported(_) => self
                         .l10n
                         .string("red-alert-command-empty-self-success", fluent_args![]),
                     RedAlertDeportationResult::NotFound => self
                         .l10n
                         .string("red-alert-command-empty-self-not-found", fluent_args![]),
+                    RedAlertDeportationResult::Immune => self
+                        .l10n
+                        .string("red-alert-command-empty-self-immune", fluent_args![]),
                     RedAlertDeportationResult::Error(error) => self.l10n.string(
                         "red-alert-command-empty-self-error",
                         fluent_args![
-                            "error" => error.to_string()
+                            "error" => deportation_error_description(&self.l10n, &error)
                         ],
                     ),
                 }
             }
-            CommonRedAlertResult::SingleSuccess { is_self_kick } => {
+            CommonRedAlertResult::SingleSuccess {
+                is_self_kick,
+                action,
+            } => {
                 self.actions_history.lock().await.log_history(
                     guild_id,
                     RedAlertActionType::Text {
                         author_id,
                         target_id: target_users_ids[0],
+                        action,
                         is_success: true,
                     },
                 );
@@ -196,20 +366,17 @@ impl Command for TextRedAlertCommand {
                     RedAlertActionType::Text {
                         author_id,
                         target_id: target_users_ids[0],
+                        action: action.clone(),
                         is_success: false,
                     },
                 );
                 if let Some(self_kick_result) = auto_self_kick_result {
                     actions_history.log_history(
                         guild_id,
-                        RedAlertActionType::Text {
-                            author_id,
-                            target_id: author_id,
-                            is_success: self_kick_result.is_deported(),
-                        },
+                        history_record(author_id, author_id, action, &self_kick_result),
                     );
                     match self_kick_result {
-                        RedAlertDeportationResult::Deported => self.l10n.string(
+                        RedAlertDeportationResult::Deported(_) => self.l10n.string(
                             "red-alert-command-single-not-found-self-success",
                             fluent_args![],
                         ),
@@ -217,10 +384,14 @@ impl Command for TextRedAlertCommand {
                             "red-alert-command-single-not-found-self-not-found",
                             fluent_args![],
                         ),
+                        RedAlertDeportationResult::Immune => self.l10n.string(
+                            "red-alert-command-single-not-found-self-immune",
+                            fluent_args![],
+                        ),
                         RedAlertDeportationResult::Error(error) => self.l10n.string(
                             "red-alert-command-single-not-found-self-error",
                             fluent_args![
-                                "error" => error.to_string()
+                                "error" => deportation_error_description(&self.l10n, &error)
                             ],
                         ),
                     }
@@ -229,6 +400,26 @@ impl Command for TextRedAlertCommand {
                         .string("red-alert-command-single-not-found-self", fluent_args![])
                 }
             }
+            CommonRedAlertResult::SingleImmune { is_self_kick } => {
+                self.actions_history.lock().await.log_history(
+                    guild_id,
+                    RedAlertActionType::Immune {
+                        author_id,
+                        target_id: target_users_ids[0],
+                    },
+                );
+                if is_self_kick {
+                    self.l10n
+                        .string("red-alert-command-single-self-immune", fluent_args![])
+                } else {
+                    self.l10n.string(
+                        "red-alert-command-single-target-immune",
+                        fluent_args![
+                            "user-name" => target_users_ids[0].mention().to_string()
+                        ],
+                    )
+                }
+            }
             CommonRedAlertResult::SingleError {
                 error,
                 is_self_kick_try,
@@ -238,6 +429,7 @@ impl Command for TextRedAlertCommand {
                     RedAlertActionType::Text {
                         author_id,
                         target_id: target_users_ids[0],
+                        action: action.clone(),
                         is_success: false,
                     },
                 );
@@ -245,14 +437,14 @@ impl Command for TextRedAlertCommand {
                     self.l10n.string(
                         "red-alert-command-single-self-error",
                         fluent_args![
-                            "error" => error.to_string()
+                            "error" => deportation_error_description(&self.l10n, &error)
                         ],
                     )
                 } else {
                     self.l10n.string(
                         "red-alert-command-single-target-error",
                         fluent_args![
-                            "error" => error.to_string()
+                            "error" => deportation_error_description(&self.l10n, &error)
                         ],
                     )
                 }
@@ -265,33 +457,33 @@ impl Command for TextRedAlertCommand {
                 for result_index in 0..results.len() {
                     actions_history.log_history(
                         guild_id,
-                        RedAlertActionType::Text {
+                        history_record(
                             author_id,
-                            target_id: target_users_ids[result_index],
-                            is_success: results[result_index].is_deported(),
-                        },
+                            target_users_ids[result_index],
+                            action,
+                            &results[result_index],
+                        ),
                     );
                 }
                 if let Some(auto_self_kick_result) = auto_self_kick_result {
                     actions_history.log_history(
                         guild_id,
-                        RedAlertActionType::Text {
-                            author_id,
-                            target_id: author_id,
-                            is_success: auto_self_kick_result.is_deported(),
-                        },
+                        history_record(author_id, author_id, action, &auto_self_kick_result),
                     );
                     match auto_self_kick_result {
-                        RedAlertDeportationResult::Deported => self
+                        RedAlertDeportationResult::Deported(_) => self
                             .l10n
                             .string("red-alert-command-mass-self-success", fluent_args![]),
                         RedAlertDeportationResult::NotFound => self
                             .l10n
                             .string("red-alert-command-mass-self-not-found", fluent_args![]),
+                        RedAlertDeportationResult::Immune => self
+                            .l10n
+                            .string("red-alert-command-mass-self-immune", fluent_args![]),
                         RedAlertDeportationResult::Error(error) => self.l10n.string(
                             "red-alert-command-mass-self-error",
                             fluent_args![
-                                "error" => error.to_string()
+                                "error" => deportation_error_description(&self.l10n, &error)
                             ],
                         ),
                     }
@@ -301,16 +493,19 @@ impl Command for TextRedAlertCommand {
                         .string("red-alert-command-mass-records-header", fluent_args![])];
                     for index in 0..results.len() {
                         let deport_status = match &results[index] {
-                            RedAlertDeportationResult::Deported => self
+                            RedAlertDeportationResult::Deported(_) => self
                                 .l10n
                                 .string("red-alert-command-mass-success-status", fluent_args![]),
                             RedAlertDeportationResult::NotFound => self
                                 .l10n
                                 .string("red-alert-command-mass-not-found-status", fluent_args![]),
+                            RedAlertDeportationResult::Immune => self
+                                .l10n
+                                .string("red-alert-command-mass-immune-status", fluent_args![]),
                             RedAlertDeportationResult::Error(error) => self.l10n.string(
                                 "red-alert-command-mass-error-status",
                                 fluent_args![
-                                    "error" => error.to_string()
+                                    "error" => deportation_error_description(&self.l10n, &error)
                                 ],
                             ),
                         };
@@ -327,6 +522,11 @@ impl Command for TextRedAlertCommand {
                 }
             }
         };
+        let answer_msg = if rate_limits_strings.is_empty() {
+            answer_msg
+        } else {
+            [rate_limits_strings, vec![answer_msg]].concat().join(NEW_LINE)
+        };
         let _ = params.channel_id.say(&ctx, answer_msg).await;
     }
 }
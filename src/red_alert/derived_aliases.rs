@@ -0,0 +1,38 @@
+use super::super::components::*;
+use super::*;
+use serenity::model::id::GuildId;
+use serenity::prelude::Context;
+use std::collections::{HashMap, HashSet};
+
+pub fn derive_aliases(ctx: &Context, guild_id: &GuildId) -> HashMap<String, RedAlertVoiceAlias<u64>> {
+    let Some(names_users_ids) = ctx.cache.guild_field(guild_id, |guild| {
+        let mut names_users_ids: HashMap<String, HashSet<u64>> = HashMap::new();
+        for (user_id, member) in &guild.members {
+            if member.user.bot {
+                continue;
+            }
+            let names = [Some(&member.user.name), member.nick.as_ref()];
+            for name in names.into_iter().flatten() {
+                for spoken_name in spoken_names(name) {
+                    names_users_ids
+                        .entry(spoken_name)
+                        .or_default()
+                        .insert(user_id.0);
+                }
+            }
+        }
+        names_users_ids
+    }) else {
+        return HashMap::new();
+    };
+    names_users_ids
+        .into_iter()
+        .filter_map(|(name, users_ids)| {
+            if users_ids.len() != 1 {
+                return None;
+            }
+            let user_id = users_ids.into_iter().next()?;
+            Some((name, RedAlertVoiceAlias::new(RedAlertVoiceTarget::User(user_id))))
+        })
+        .collect()
+}
@@ -2,12 +2,19 @@ use super::*;
 use serde::{Deserialize, Serialize};
 use serenity::model::id::GuildId;
 use std::collections::{HashMap, HashSet};
+use std::sync::Arc;
 
-#[derive(Debug, PartialEq, Serialize, Deserialize)]
+#[derive(Serialize, Deserialize)]
 pub struct RedAlertGuildsVoiceConfig {
     auto_track_ids: HashSet<u64>,
     base: RedAlertVoiceConfig<u64>,
     specific: HashMap<u64, RedAlertVoiceConfig<u64>>,
+    #[serde(skip)]
+    derived_aliases: HashMap<u64, HashMap<String, RedAlertVoiceAlias<u64>>>,
+    #[serde(skip)]
+    base_match_index: Option<Arc<RedAlertVoiceMatchIndex<u64>>>,
+    #[serde(skip)]
+    specific_match_indexes: HashMap<u64, Arc<RedAlertVoiceMatchIndex<u64>>>,
 }
 
 impl RedAlertGuildsVoiceConfig {
@@ -15,8 +22,13 @@ impl RedAlertGuildsVoiceConfig {
     pub fn read() -> RedAlertGuildsVoiceConfig {
         let config_string =
             std::fs::read_to_string(Self::CONFIG_PATH).expect("Guild voice config read error!");
-        let config: RedAlertGuildsVoiceConfig =
+        let mut config: RedAlertGuildsVoiceConfig =
             serde_yaml::from_str(&config_string).expect("Guild voice config deserialize error!");
+        config.base_match_index = Some(Arc::new(RedAlertVoiceMatchIndex::new(&config.base, None)));
+        let guilds_ids: Vec<u64> = config.specific.keys().cloned().collect();
+        for guild_id in guilds_ids {
+            config.update_match_index(GuildId(guild_id));
+        }
         config
     }
     pub fn write(&self) {
@@ -27,13 +39,74 @@ impl RedAlertGuildsVoiceConfig {
     pub fn get(&self, guild_id: &GuildId) -> &RedAlertVoiceConfig<u64> {
         self.specific.get(&guild_id.0).unwrap_or(&self.base)
     }
+    pub fn match_index(&self, guild_id: &GuildId) -> Arc<RedAlertVoiceMatchIndex<u64>> {
+        if let Some(match_index) = self
+            .specific_match_indexes
+            .get(&guild_id.0)
+            .or(self.base_match_index.as_ref())
+        {
+            return match_index.clone();
+        }
+        Arc::new(RedAlertVoiceMatchIndex::new(
+            self.get(guild_id),
+            self.derived_aliases(guild_id),
+        ))
+    }
+    fn update_match_index(&mut self, guild_id: GuildId) {
+        if !self.specific.contains_key(&guild_id.0) && self.derived_aliases(&guild_id).is_none() {
+            self.specific_match_indexes.remove(&guild_id.0);
+            return;
+        }
+        let match_index =
+            RedAlertVoiceMatchIndex::new(self.get(&guild_id), self.derived_aliases(&guild_id));
+        self.specific_match_indexes
+            .insert(guild_id.0, Arc::new(match_index));
+    }
     pub fn remove(&mut self, guild_id: &GuildId) -> RedAlertVoiceConfig<u64> {
-        self.specific
+        let guild_voice_config = self
+            .specific
             .remove(&guild_id.0)
-            .unwrap_or(self.base.clone())
+            .unwrap_or(self.base.clone());
+        self.update_match_index(*guild_id);
+        guild_voice_config
     }
     pub fn insert(&mut self, guild_id: GuildId, guild_voice_config: RedAlertVoiceConfig<u64>) {
         self.specific.insert(guild_id.0, guild_voice_config);
+        self.update_match_index(guild_id);
+    }
+    pub fn derived_aliases(
+        &self,
+        guild_id: &GuildId,
+    ) -> Option<&HashMap<String, RedAlertVoiceAlias<u64>>> {
+        if !self.get(guild_id).derive_aliases {
+            return None;
+        }
+        self.derived_aliases.get(&guild_id.0)
+    }
+    pub fn is_derived_aliases_changed(
+        &self,
+        guild_id: &GuildId,
+        derived_aliases: &HashMap<String, RedAlertVoiceAlias<u64>>,
+    ) -> bool {
+        self.derived_aliases
+            .get(&guild_id.0)
+            .map_or(!derived_aliases.is_empty(), |current| current != derived_aliases)
+    }
+    /// Rebuilds the guild match index only if the derived aliases changed.
+    pub fn set_derived_aliases(
+        &mut self,
+        guild_id: GuildId,
+        derived_aliases: HashMap<String, RedAlertVoiceAlias<u64>>,
+    ) {
+        if !self.is_derived_aliases_changed(&guild_id, &derived_aliases) {
+            return;
+        }
+        if derived_aliases.is_empty() {
+            self.derived_aliases.remove(&guild_id.0);
+        } else {
+            self.derived_aliases.insert(guild_id.0, derived_aliases);
+        }
+        self.update_match_index(guild_id);
     }
     pub fn switch_auto_track(&mut self, guild_id: GuildId) -> bool {
         if self.auto_track_ids.remove(&guild_id.0) {
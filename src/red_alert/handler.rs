@@ -1,63 +1,328 @@
+use super::super::components::*;
+use super::*;
+use serenity::futures::{stream, StreamExt};
 use serenity::model::prelude::*;
 use serenity::prelude::*;
+use std::future::Future;
+use std::sync::Arc;
+use tokio::sync::{Mutex, RwLock};
+
+const MAX_CONCURRENT_DEPORTATIONS: usize = 4;
+
+/// Runs `perform` for every id with at most `limit` of them in flight, results keep the order of `ids`.
+async fn ordered_concurrently<I, F, Fut>(ids: I, limit: usize, perform: F) -> Vec<Fut::Output>
+where
+    I: IntoIterator,
+    F: FnMut(I::Item) -> Fut,
+    Fut: Future,
+{
+    stream::iter(ids)
+        .map(perform)
+        .buffered(limit)
+        .collect()
+        .await
+}
 
 #[derive(Debug)]
 pub enum RedAlertDeportationResult {
-    Deported,
+    Deported(RedAlertActionKind),
     NotFound,
+    Immune,
     Error(SerenityError),
 }
 
 impl RedAlertDeportationResult {
     pub fn is_deported(&self) -> bool {
         match self {
-            RedAlertDeportationResult::Deported => true,
-            RedAlertDeportationResult::NotFound | RedAlertDeportationResult::Error(_) => false,
+            RedAlertDeportationResult::Deported(_) => true,
+            RedAlertDeportationResult::NotFound
+            | RedAlertDeportationResult::Immune
+            | RedAlertDeportationResult::Error(_) => false,
         }
     }
     pub fn is_not_found(&self) -> bool {
         match self {
             RedAlertDeportationResult::NotFound => true,
-            RedAlertDeportationResult::Deported | RedAlertDeportationResult::Error(_) => false,
+            RedAlertDeportationResult::Deported(_)
+            | RedAlertDeportationResult::Immune
+            | RedAlertDeportationResult::Error(_) => false,
+        }
+    }
+    pub fn is_immune(&self) -> bool {
+        match self {
+            RedAlertDeportationResult::Immune => true,
+            RedAlertDeportationResult::Deported(_)
+            | RedAlertDeportationResult::NotFound
+            | RedAlertDeportationResult::Error(_) => false,
+        }
+    }
+    pub fn action(&self) -> Option<&RedAlertActionKind> {
+        match self {
+            RedAlertDeportationResult::Deported(action) => Some(action),
+            RedAlertDeportationResult::NotFound
+            | RedAlertDeportationResult::Immune
+            | RedAlertDeportationResult::Error(_) => None,
         }
     }
 }
 
-pub struct RedAlertHandler;
+pub struct RedAlertHandler {
+    pub(super) offenses: Arc<Mutex<RedAlertOffenses>>,
+    pub(super) actions_history: Arc<Mutex<RedAlertActionsHistory>>,
+    pub(super) voice_states: RwLock<RedAlertVoiceStates>,
+    pub(super) l10n: L10n,
+}
 
 impl RedAlertHandler {
+    pub fn new(l10n: L10n) -> Self {
+        Self {
+            offenses: Arc::new(Mutex::new(RedAlertOffenses::read())),
+            actions_history: Default::default(),
+            voice_states: Default::default(),
+            l10n,
+        }
+    }
+
+    /// Punishes users concurrently, results keep the order of `users_ids`.
+    /// Requests of one guild share a Discord rate-limit bucket, which the serenity ratelimiter waits for.
     pub async fn multiple(
         &self,
         ctx: &Context,
         guild_id: &GuildId,
         users_ids: &Vec<UserId>,
+        action: &RedAlertActionKind,
+        trigger: &RedAlertTrigger,
+        guild_voice_config: &RedAlertVoiceConfig<u64>,
     ) -> Vec<RedAlertDeportationResult> {
-        let mut results = Vec::with_capacity(users_ids.capacity());
-        for index in 0..users_ids.len() {
-            results.push(self.single(ctx, guild_id, &users_ids[index]).await);
-        }
-        results
+        let users_ids = users_ids.iter().copied();
+        ordered_concurrently(
+            users_ids,
+            MAX_CONCURRENT_DEPORTATIONS,
+            |user_id| async move {
+                self.single(ctx, guild_id, &user_id, action, trigger, guild_voice_config)
+                    .await
+            },
+        )
+        .await
     }
 
+    /// The punishment the target gets now, repeat offenders climb the escalation ladder.
+    pub async fn escalated_action(
+        &self,
+        guild_id: &GuildId,
+        user_id: &UserId,
+        action: &RedAlertActionKind,
+        guild_voice_config: &RedAlertVoiceConfig<u64>,
+    ) -> RedAlertActionKind {
+        match &guild_voice_config.escalation {
+            Some(escalation) => self
+                .offenses
+                .lock()
+                .await
+                .escalated_action(guild_id, user_id, escalation)
+                .unwrap_or_else(|| action.clone()),
+            None => action.clone(),
+        }
+    }
     pub async fn single(
         &self,
         ctx: &Context,
         guild_id: &GuildId,
         user_id: &UserId,
+        action: &RedAlertActionKind,
+        trigger: &RedAlertTrigger,
+        guild_voice_config: &RedAlertVoiceConfig<u64>,
     ) -> RedAlertDeportationResult {
-        if {
-            if let Some(guild) = ctx.cache.guild(guild_id) {
-                guild.voice_states.get(&user_id).is_some()
-            } else {
-                false
+        if self
+            .is_immune(ctx, guild_id, user_id, &guild_voice_config.immunity)
+            .await
+        {
+            return RedAlertDeportationResult::Immune;
+        }
+        let cached_voice_channel_id = ctx.cache.guild(guild_id).and_then(|guild| {
+            guild
+                .voice_states
+                .get(user_id)
+                .and_then(|voice_state| voice_state.channel_id)
+        });
+        // Both may miss after a restart, Discord then reports a disconnected or moved target
+        // that is not connected, other punishments aren't tried without a known voice state.
+        let voice_channel_id = match cached_voice_channel_id {
+            Some(voice_channel_id) => Some(voice_channel_id),
+            None => self.voice_states.read().await.channel_id(guild_id, user_id),
+        };
+        let action = self
+            .escalated_action(guild_id, user_id, action, guild_voice_config)
+            .await;
+        if voice_channel_id.is_none() && !action.is_voice_move() {
+            return RedAlertDeportationResult::NotFound;
+        }
+        if let Err(RedAlertPreflightError::MissingPermissions(permissions)) = preflight_permissions(
+            ctx,
+            guild_id,
+            voice_channel_id.as_ref(),
+            action.required_permissions(),
+        )
+        .await
+        {
+            return RedAlertDeportationResult::Error(SerenityError::Model(
+                ModelError::InvalidPermissions(permissions),
+            ));
+        }
+        let author_id = trigger.author_id();
+        let author_name = match author_id.to_user(ctx).await {
+            Ok(author) => author.tag(),
+            Err(_) => author_id.to_string(),
+        };
+        let audit_log_reason = trigger.audit_log_reason(&self.l10n, &author_name);
+        let retry_policy = &guild_voice_config.retry;
+        let mut attempt: u32 = 1;
+        let perform_result = loop {
+            let Err(error) = action
+                .action(&self.l10n)
+                .perform(ctx, guild_id, user_id, &audit_log_reason)
+                .await
+            else {
+                break Ok(());
+            };
+            if is_target_not_connected(&error) {
+                break Err(error);
             }
-        } {
-            match guild_id.disconnect_member(&ctx, user_id).await {
-                Ok(_) => RedAlertDeportationResult::Deported,
-                Err(err) => RedAlertDeportationResult::Error(err),
+            let will_retry = attempt < retry_policy.max_attempts && is_retryable(&error);
+            warn!(
+                "[GID:{}][UID:{}] Punishment {} attempt {}/{} FAILED, will retry: {}, error: {}.",
+                guild_id,
+                user_id,
+                action.name(),
+                attempt,
+                retry_policy.max_attempts,
+                will_retry,
+                error
+            );
+            self.actions_history.lock().await.log_history(
+                *guild_id,
+                RedAlertActionType::FailedAttempt {
+                    target_id: *user_id,
+                    action: action.clone(),
+                    attempt,
+                    max_attempts: retry_policy.max_attempts,
+                    error: error.to_string(),
+                    will_retry,
+                },
+            );
+            if !will_retry {
+                break Err(error);
             }
-        } else {
-            RedAlertDeportationResult::NotFound
+            tokio::time::sleep(retry_policy.delay(attempt)).await;
+            attempt += 1;
+        };
+        match perform_result {
+            Ok(_) => {
+                if let Some(escalation) = &guild_voice_config.escalation {
+                    let mut offenses = self.offenses.lock().await;
+                    offenses.register(*guild_id, *user_id, escalation);
+                    offenses.write().await;
+                }
+                if guild_voice_config.notification.enabled && action != RedAlertActionKind::Warn {
+                    notify_target(
+                        ctx,
+                        &self.l10n,
+                        guild_id,
+                        user_id,
+                        &action,
+                        trigger,
+                        guild_voice_config,
+                    )
+                    .await;
+                }
+                RedAlertDeportationResult::Deported(action)
+            }
+            Err(err) if is_target_not_connected(&err) => RedAlertDeportationResult::NotFound,
+            Err(err) => RedAlertDeportationResult::Error(err),
         }
     }
+
+    async fn is_immune(
+        &self,
+        ctx: &Context,
+        guild_id: &GuildId,
+        user_id: &UserId,
+        immunity: &RedAlertVoiceImmunity<u64>,
+    ) -> bool {
+        if immunity.users_ids.contains(&user_id.0) {
+            return true;
+        }
+        if immunity.roles_ids.is_empty() {
+            return false;
+        }
+        let roles = match guild_id.member(ctx, user_id).await {
+            Ok(member) => member.roles,
+            Err(error) => {
+                let cached_roles = ctx
+                    .cache
+                    .member_field(guild_id, user_id, |member| member.roles.clone());
+                let Some(roles) = cached_roles else {
+                    warn!(
+                        "[GID:{}][UID:{}] Immunity roles check failed, treated as immune: {:?}.",
+                        guild_id, user_id, error
+                    );
+                    return true;
+                };
+                roles
+            }
+        };
+        roles
+            .iter()
+            .any(|role_id| immunity.roles_ids.contains(&role_id.0))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::sync::atomic::{AtomicUsize, Ordering};
+    use std::time::Duration;
+
+    #[tokio::test]
+    async fn ordered_concurrently_keeps_order_and_limit() {
+        let delays_ms: Vec<u64> = vec![40, 10, 30, 0, 20, 5, 15];
+        let limit = 3;
+        let in_flight = AtomicUsize::new(0);
+        let max_in_flight = AtomicUsize::new(0);
+        let results =
+            ordered_concurrently(delays_ms.iter().enumerate(), limit, |(index, delay_ms)| {
+                let in_flight = &in_flight;
+                let max_in_flight = &max_in_flight;
+                async move {
+                    let current = in_flight.fetch_add(1, Ordering::SeqCst) + 1;
+                    max_in_flight.fetch_max(current, Ordering::SeqCst);
+                    tokio::time::sleep(Duration::from_millis(*delay_ms)).await;
+                    in_flight.fetch_sub(1, Ordering::SeqCst);
+                    index
+                }
+            })
+            .await;
+        assert_eq!(results, (0..delays_ms.len()).collect::<Vec<usize>>());
+        assert_eq!(max_in_flight.load(Ordering::SeqCst), limit);
+    }
+
+    #[tokio::test]
+    async fn ordered_concurrently_runs_one_at_a_time_with_limit_one() {
+        let max_in_flight = AtomicUsize::new(0);
+        let in_flight = AtomicUsize::new(0);
+        let results = ordered_concurrently([30u64, 0, 10], 1, |delay_ms| {
+            let in_flight = &in_flight;
+            let max_in_flight = &max_in_flight;
+            async move {
+                let current = in_flight.fetch_add(1, Ordering::SeqCst) + 1;
+                max_in_flight.fetch_max(current, Ordering::SeqCst);
+                tokio::time::sleep(Duration::from_millis(delay_ms)).await;
+                in_flight.fetch_sub(1, Ordering::SeqCst);
+                delay_ms
+            }
+        })
+        .await;
+        assert_eq!(results, vec![30, 0, 10]);
+        assert_eq!(max_in_flight.load(Ordering::SeqCst), 1);
+    }
 }
@@ -1,4 +1,5 @@
 use super::super::components::*;
+use super::*;
 use serenity::model::id::GuildId;
 use serenity::model::prelude::ChannelId;
 use serenity::prelude::Context;
@@ -9,6 +10,7 @@ use tokio::sync::RwLock;
 
 pub enum StartListenError {
     SongbirdMissing,
+    Preflight(RedAlertPreflightError),
     ConnectingError,
 }
 
@@ -21,6 +23,16 @@ pub async fn start_listen(
     let Some(manager) = songbird::get(ctx).await else {
         return Err(StartListenError::SongbirdMissing);
     };
+    match preflight_permissions(ctx, &guild_id, Some(&channel_id), LISTEN_PERMISSIONS).await {
+        Ok(()) => {}
+        Err(error @ RedAlertPreflightError::MissingPermissions(_)) => {
+            return Err(StartListenError::Preflight(error));
+        }
+        Err(error) => warn!(
+            "[GID:{}] Listen permissions preflight skipped, joining anyway: {:?}.",
+            guild_id, error
+        ),
+    }
     let (handler_lock, connection_result) = manager.join(guild_id, channel_id).await;
     if !connection_result.is_ok() {
         return Err(StartListenError::ConnectingError);
@@ -1,20 +1,50 @@
+mod action;
 mod actions_history;
 mod commands_handler;
+mod derived_aliases;
 mod guilds_voice_config;
 mod handler;
 mod listen_actions;
 mod monitoring_performer;
+mod near_misses_history;
+mod notification;
+mod offenses;
+mod pending_actions;
+mod permissions_preflight;
+mod rate_limiter;
 mod recognizer_performer;
+mod retry;
+mod siren;
+mod trigger;
 mod voice_config;
+mod voice_config_entries;
+mod voice_match_index;
+mod voice_states;
+mod vote;
 
+pub use action::*;
 use actions_history::*;
 pub use commands_handler::*;
+use derived_aliases::*;
 use guilds_voice_config::*;
 pub use handler::*;
 use listen_actions::*;
 use monitoring_performer::*;
+use near_misses_history::*;
+use notification::*;
+pub use offenses::*;
+use pending_actions::*;
+use permissions_preflight::*;
+use rate_limiter::*;
 use recognizer_performer::*;
-use voice_config::*;
+use retry::*;
+use siren::*;
+use trigger::*;
+pub use voice_config::*;
+use voice_config_entries::*;
+pub use voice_match_index::*;
+use voice_states::*;
+use vote::*;
 
 pub(super) const NEW_LINE: &'static str = "\n";
 pub(super) const SPACE: &'static str = " ";
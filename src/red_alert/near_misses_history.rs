@@ -0,0 +1,63 @@
+use super::*;
+use chrono::{offset, DateTime, Utc};
+use serenity::model::id::GuildId;
+use serenity::model::prelude::UserId;
+use std::collections::{HashMap, VecDeque};
+
+#[derive(Clone)]
+pub struct RedAlertNearMiss {
+    pub time: DateTime<Utc>,
+    pub author_id: UserId,
+    pub full_text: String,
+    pub word: String,
+    pub targets: Vec<RedAlertVoiceTarget<u64>>,
+    pub similarity: f32,
+    pub similarity_threshold: f32,
+}
+
+impl RedAlertNearMiss {
+    pub fn new(
+        author_id: UserId,
+        full_text: String,
+        rule_report: &RedAlertVoiceRuleReport<&u64>,
+    ) -> Self {
+        Self {
+            time: offset::Utc::now(),
+            author_id,
+            full_text,
+            word: rule_report.word.clone(),
+            targets: rule_report
+                .targets
+                .iter()
+                .map(|target| match target {
+                    RedAlertVoiceTarget::User(user_id) => RedAlertVoiceTarget::User(**user_id),
+                    RedAlertVoiceTarget::Role(role_id) => RedAlertVoiceTarget::Role(**role_id),
+                })
+                .collect(),
+            similarity: rule_report.similarity(),
+            similarity_threshold: rule_report.similarity_threshold(),
+        }
+    }
+}
+
+#[derive(Default)]
+pub struct RedAlertNearMissesHistory(HashMap<GuildId, VecDeque<RedAlertNearMiss>>);
+
+impl RedAlertNearMissesHistory {
+    pub fn log_near_miss(&mut self, guild_id: GuildId, near_miss: RedAlertNearMiss) {
+        if let Some(guild_near_misses) = self.0.get_mut(&guild_id) {
+            guild_near_misses.push_back(near_miss);
+            if guild_near_misses.len() > 100 {
+                guild_near_misses.pop_front();
+            }
+        } else {
+            self.0.insert(guild_id, VecDeque::from([near_miss]));
+        }
+    }
+    pub fn get(&self, guild_id: &GuildId) -> Option<&VecDeque<RedAlertNearMiss>> {
+        self.0.get(guild_id)
+    }
+    pub fn clear(&mut self, guild_id: &GuildId) {
+        self.0.remove(guild_id);
+    }
+}
@@ -0,0 +1,87 @@
+use super::super::components::*;
+use super::*;
+use serde::{Deserialize, Serialize};
+use serenity::model::id::GuildId;
+use serenity::model::prelude::{ChannelId, UserId};
+use serenity::prelude::{Context, Mentionable};
+
+#[derive(Clone, Debug, Default, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct RedAlertNotification {
+    pub enabled: bool,
+    pub appeal: Option<String>,
+}
+
+/// Explains the punishment to the target in DM, or in the log channel if DMs are closed.
+pub async fn notify_target(
+    ctx: &Context,
+    l10n: &L10n,
+    guild_id: &GuildId,
+    target_id: &UserId,
+    action: &RedAlertActionKind,
+    trigger: &RedAlertTrigger,
+    guild_voice_config: &RedAlertVoiceConfig<u64>,
+) {
+    let guild_name = guild_id
+        .name(&ctx.cache)
+        .unwrap_or_else(|| guild_id.to_string());
+    let cause = match trigger {
+        RedAlertTrigger::Voice { phrase, .. } => l10n.string(
+            "red-alert-notification-voice-cause",
+            fluent_args![
+                "phrase" => phrase.as_str()
+            ],
+        ),
+        RedAlertTrigger::Text { author_id } => l10n.string(
+            "red-alert-notification-text-cause",
+            fluent_args![
+                "author-name" => author_id.mention().to_string()
+            ],
+        ),
+    };
+    let appeal = match &guild_voice_config.notification.appeal {
+        Some(appeal) => appeal.clone(),
+        None => l10n.string("red-alert-notification-default-appeal", fluent_args![]),
+    };
+    let message = l10n.string(
+        "red-alert-notification-message",
+        fluent_args![
+            "guild-name" => guild_name,
+            "action" => action.description(l10n),
+            "cause" => cause,
+            "appeal" => appeal
+        ],
+    );
+    let dm_error = match target_id.create_dm_channel(ctx).await {
+        Ok(dm_channel) => match dm_channel.say(ctx, &message).await {
+            Ok(_) => return,
+            Err(error) => error,
+        },
+        Err(error) => error,
+    };
+    let Some(log_channel_id) = guild_voice_config.log_channel_id else {
+        warn!(
+            "[GID:{}][UID:{}] Punishment notification FAILED, no log channel, DM error: {}.",
+            guild_id, target_id, dm_error
+        );
+        return;
+    };
+    if let Err(error) = ChannelId(log_channel_id)
+        .say(
+            ctx,
+            l10n.string(
+                "red-alert-notification-fallback-message",
+                fluent_args![
+                    "target-name" => target_id.mention().to_string(),
+                    "message" => message
+                ],
+            ),
+        )
+        .await
+    {
+        warn!(
+            "[GID:{}][UID:{}] Punishment notification FAILED, DM error: {}, log channel error: {}.",
+            guild_id, target_id, dm_error, error
+        );
+    }
+}
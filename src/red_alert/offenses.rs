@@ -0,0 +1,122 @@
+use super::*;
+use chrono::offset;
+use serde::{Deserialize, Serialize};
+use serenity::model::id::GuildId;
+use serenity::model::prelude::UserId;
+use std::collections::HashMap;
+
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+pub struct RedAlertEscalationPolicy {
+    pub steps: Vec<RedAlertActionKind>,
+    #[serde(default)]
+    pub window_minutes: Option<u64>,
+}
+
+/// Offenses timestamps (unix seconds) of every punished user, persisted between restarts.
+#[derive(Default, Serialize, Deserialize)]
+pub struct RedAlertOffenses(HashMap<u64, HashMap<u64, Vec<i64>>>);
+
+impl RedAlertOffenses {
+    const OFFENSES_PATH: &str = "offenses.yaml";
+    pub fn read() -> RedAlertOffenses {
+        let Ok(offenses_string) = std::fs::read_to_string(Self::OFFENSES_PATH) else {
+            return Default::default();
+        };
+        serde_yaml::from_str(&offenses_string).expect("Offenses deserialize error!")
+    }
+    /// Writes on the blocking pool, callers keep the offenses locked so writes stay ordered.
+    pub async fn write(&self) {
+        let offenses_string = serde_yaml::to_string(self).expect("Offenses serialize error!");
+        match tokio::task::spawn_blocking(move || {
+            std::fs::write(Self::OFFENSES_PATH, offenses_string)
+        })
+        .await
+        {
+            Ok(Ok(_)) => {}
+            Ok(Err(error)) => warn!("Offenses write error: {}.", error),
+            Err(error) => warn!("Offenses write task error: {}.", error),
+        }
+    }
+    fn decay(&mut self, guild_id: &GuildId, window_minutes: Option<u64>) {
+        let Some(window_minutes) = window_minutes else {
+            return;
+        };
+        let Some(guild_offenses) = self.0.get_mut(&guild_id.0) else {
+            return;
+        };
+        let oldest_time = offset::Utc::now().timestamp() - (window_minutes * 60) as i64;
+        for user_offenses in guild_offenses.values_mut() {
+            user_offenses.retain(|time| *time > oldest_time);
+        }
+        guild_offenses.retain(|_, user_offenses| !user_offenses.is_empty());
+    }
+    pub fn count(
+        &mut self,
+        guild_id: &GuildId,
+        user_id: &UserId,
+        window_minutes: Option<u64>,
+    ) -> usize {
+        self.decay(guild_id, window_minutes);
+        self.0
+            .get(&guild_id.0)
+            .and_then(|guild_offenses| guild_offenses.get(&user_id.0))
+            .map_or(0, Vec::len)
+    }
+    pub fn guild_counts(
+        &mut self,
+        guild_id: &GuildId,
+        window_minutes: Option<u64>,
+    ) -> Vec<(UserId, usize)> {
+        self.decay(guild_id, window_minutes);
+        let mut counts: Vec<(UserId, usize)> = self
+            .0
+            .get(&guild_id.0)
+            .into_iter()
+            .flatten()
+            .map(|(user_id, user_offenses)| (UserId(*user_id), user_offenses.len()))
+            .collect();
+        counts.sort_by(|a, b| b.1.cmp(&a.1));
+        counts
+    }
+    /// Picks the ladder step for the next offense of the user, the last step repeats.
+    pub fn escalated_action(
+        &mut self,
+        guild_id: &GuildId,
+        user_id: &UserId,
+        policy: &RedAlertEscalationPolicy,
+    ) -> Option<RedAlertActionKind> {
+        let count = self.count(guild_id, user_id, policy.window_minutes);
+        policy
+            .steps
+            .get(count.min(policy.steps.len().saturating_sub(1)))
+            .cloned()
+    }
+    /// Keeps only offenses which can still change the ladder step: the ones inside the window,
+    /// and no more than the steps count, since the last step repeats.
+    pub fn register(
+        &mut self,
+        guild_id: GuildId,
+        user_id: UserId,
+        policy: &RedAlertEscalationPolicy,
+    ) {
+        self.decay(&guild_id, policy.window_minutes);
+        let user_offenses = self
+            .0
+            .entry(guild_id.0)
+            .or_default()
+            .entry(user_id.0)
+            .or_default();
+        user_offenses.push(offset::Utc::now().timestamp());
+        let excess_count = user_offenses.len().saturating_sub(policy.steps.len().max(1));
+        user_offenses.drain(..excess_count);
+    }
+    pub fn reset(&mut self, guild_id: &GuildId, user_id: Option<&UserId>) {
+        let Some(user_id) = user_id else {
+            self.0.remove(&guild_id.0);
+            return;
+        };
+        if let Some(guild_offenses) = self.0.get_mut(&guild_id.0) {
+            guild_offenses.remove(&user_id.0);
+        }
+    }
+}
@@ -0,0 +1,250 @@
+use super::super::components::*;
+use super::*;
+use serde::{Deserialize, Serialize};
+use serenity::model::id::GuildId;
+use serenity::model::interactions::message_component::ButtonStyle;
+use serenity::model::interactions::InteractionResponseType;
+use serenity::model::prelude::{ChannelId, UserId};
+use serenity::prelude::{Context, Mentionable};
+use std::collections::HashMap;
+use std::sync::Arc;
+use std::time::{Duration, Instant};
+use tokio::sync::oneshot::{channel, Receiver, Sender};
+use tokio::sync::Mutex;
+
+const CANCEL_BUTTON_ID: &str = "red-alert-countdown-cancel";
+
+/// Zero seconds disables the countdown.
+#[derive(Clone, Debug, Default, PartialEq, Serialize, Deserialize)]
+pub struct RedAlertCountdown {
+    #[serde(default)]
+    pub seconds: u64,
+    #[serde(default)]
+    pub cancel_phrases: Vec<String>,
+}
+
+impl RedAlertCountdown {
+    pub const MAX_SECONDS: u64 = 600;
+
+    /// Longer values from a hand edited config are cut to the maximum.
+    pub fn delay(&self) -> Duration {
+        Duration::from_secs(self.seconds.min(Self::MAX_SECONDS))
+    }
+    pub fn is_cancel_phrase(&self, normalization: &TextNormalization, text: &str) -> bool {
+        let text = format!(" {} ", normalization.normalize_phrase(text));
+        self.cancel_phrases.iter().any(|cancel_phrase| {
+            text.contains(&format!(
+                " {} ",
+                normalization.normalize_phrase(cancel_phrase)
+            ))
+        })
+    }
+}
+
+#[derive(Clone, Copy, Debug)]
+pub enum RedAlertCancelReason {
+    Phrase,
+    Button(UserId),
+    Command(UserId),
+}
+
+impl RedAlertCancelReason {
+    pub fn description(&self, l10n: &L10n) -> String {
+        match self {
+            RedAlertCancelReason::Phrase => {
+                l10n.string("red-alert-countdown-cancel-reason-phrase", fluent_args![])
+            }
+            RedAlertCancelReason::Button(user_id) => l10n.string(
+                "red-alert-countdown-cancel-reason-button",
+                fluent_args![
+                    "user-name" => user_id.mention().to_string()
+                ],
+            ),
+            RedAlertCancelReason::Command(user_id) => l10n.string(
+                "red-alert-countdown-cancel-reason-command",
+                fluent_args![
+                    "user-name" => user_id.mention().to_string()
+                ],
+            ),
+        }
+    }
+}
+
+pub struct RedAlertPendingAction {
+    pub author_id: UserId,
+    pub action: RedAlertActionKind,
+    pub deadline: Instant,
+    cancel_sender: Sender<RedAlertCancelReason>,
+}
+
+/// Punishments waiting for their countdown, keyed by guild and target.
+#[derive(Default)]
+pub struct RedAlertPendingActions(HashMap<GuildId, HashMap<UserId, RedAlertPendingAction>>);
+
+impl RedAlertPendingActions {
+    /// Returns nothing if the target already has a pending punishment or the delay is out of range.
+    pub fn schedule(
+        &mut self,
+        guild_id: GuildId,
+        target_id: UserId,
+        author_id: UserId,
+        action: RedAlertActionKind,
+        delay: Duration,
+    ) -> Option<Receiver<RedAlertCancelReason>> {
+        let deadline = Instant::now().checked_add(delay)?;
+        let guild_pending_actions = self.0.entry(guild_id).or_default();
+        if guild_pending_actions.contains_key(&target_id) {
+            return None;
+        }
+        let (cancel_sender, cancel_receiver) = channel::<RedAlertCancelReason>();
+        guild_pending_actions.insert(
+            target_id,
+            RedAlertPendingAction {
+                author_id,
+                action,
+                deadline,
+                cancel_sender,
+            },
+        );
+        Some(cancel_receiver)
+    }
+    pub fn cancel(
+        &mut self,
+        guild_id: &GuildId,
+        target_id: &UserId,
+        reason: RedAlertCancelReason,
+    ) -> bool {
+        let Some(pending_action) = self.complete(guild_id, target_id) else {
+            return false;
+        };
+        pending_action.cancel_sender.send(reason).is_ok()
+    }
+    pub fn complete(
+        &mut self,
+        guild_id: &GuildId,
+        target_id: &UserId,
+    ) -> Option<RedAlertPendingAction> {
+        let guild_pending_actions = self.0.get_mut(guild_id)?;
+        let pending_action = guild_pending_actions.remove(target_id);
+        if guild_pending_actions.is_empty() {
+            self.0.remove(guild_id);
+        }
+        pending_action
+    }
+    pub fn guild_pending(&self, guild_id: &GuildId) -> Vec<(UserId, &RedAlertPendingAction)> {
+        let mut guild_pending_actions: Vec<(UserId, &RedAlertPendingAction)> = self
+            .0
+            .get(guild_id)
+            .into_iter()
+            .flatten()
+            .map(|(target_id, pending_action)| (*target_id, pending_action))
+            .collect();
+        guild_pending_actions.sort_by_key(|(_, pending_action)| pending_action.deadline);
+        guild_pending_actions
+    }
+}
+
+/// Announces a scheduled punishment and waits for the countdown, returns the reason if it was cancelled.
+pub async fn countdown(
+    ctx: &Context,
+    l10n: &L10n,
+    pending_actions: &Arc<Mutex<RedAlertPendingActions>>,
+    guild_id: &GuildId,
+    target_id: &UserId,
+    cancel_receiver: Receiver<RedAlertCancelReason>,
+    guild_voice_config: &RedAlertVoiceConfig<u64>,
+) -> Option<RedAlertCancelReason> {
+    let delay = guild_voice_config.countdown.delay();
+    let mut message = match guild_voice_config.log_channel_id {
+        Some(log_channel_id) => ChannelId(log_channel_id)
+            .send_message(ctx, |m| {
+                m.content(l10n.string(
+                    "red-alert-countdown-message",
+                    fluent_args![
+                        "target-name" => target_id.mention().to_string(),
+                        "seconds" => delay.as_secs()
+                    ],
+                ))
+                .components(|c| {
+                    c.create_action_row(|r| {
+                        r.create_button(|b| {
+                            b.custom_id(CANCEL_BUTTON_ID)
+                                .label(l10n.string(
+                                    "red-alert-countdown-cancel-button",
+                                    fluent_args![],
+                                ))
+                                .style(ButtonStyle::Danger)
+                        })
+                    })
+                })
+            })
+            .await
+            .ok(),
+        None => None,
+    };
+    let button_cancel = async {
+        let Some(message) = &message else {
+            return std::future::pending::<UserId>().await;
+        };
+        loop {
+            let Some(interaction) = message
+                .await_component_interaction(ctx)
+                .timeout(delay)
+                .await
+            else {
+                return std::future::pending::<UserId>().await;
+            };
+            if guild_voice_config
+                .editors
+                .as_ref()
+                .map_or_else(|| true, |e| e.contains(&interaction.user.id.0))
+            {
+                let _ = interaction.defer(ctx).await;
+                return interaction.user.id;
+            }
+            let _ = interaction
+                .create_interaction_response(ctx, |r| {
+                    r.kind(InteractionResponseType::ChannelMessageWithSource)
+                        .interaction_response_data(|d| {
+                            d.content(
+                                l10n.string("red-alert-countdown-no-access", fluent_args![]),
+                            )
+                            .ephemeral(true)
+                        })
+                })
+                .await;
+        }
+    };
+    let cancel_reason = tokio::select! {
+        _ = tokio::time::sleep(delay) => None,
+        cancel_reason = cancel_receiver => cancel_reason.ok(),
+        user_id = button_cancel => Some(RedAlertCancelReason::Button(user_id)),
+    };
+    match cancel_reason {
+        None | Some(RedAlertCancelReason::Button(_)) => {
+            pending_actions.lock().await.complete(guild_id, target_id);
+        }
+        Some(RedAlertCancelReason::Phrase) | Some(RedAlertCancelReason::Command(_)) => {}
+    }
+    if let Some(message) = &mut message {
+        let result_text = match &cancel_reason {
+            Some(cancel_reason) => l10n.string(
+                "red-alert-countdown-cancelled",
+                fluent_args![
+                    "target-name" => target_id.mention().to_string(),
+                    "reason" => cancel_reason.description(l10n)
+                ],
+            ),
+            None => l10n.string(
+                "red-alert-countdown-elapsed",
+                fluent_args![
+                    "target-name" => target_id.mention().to_string()
+                ],
+            ),
+        };
+        let _ = message
+            .edit(ctx, |m| m.content(result_text).components(|c| c))
+            .await;
+    }
+    cancel_reason
+}
@@ -0,0 +1,132 @@
+use super::super::components::*;
+use super::*;
+use serenity::model::id::GuildId;
+use serenity::model::prelude::{Channel, ChannelId, Permissions};
+use serenity::model::ModelError;
+use serenity::prelude::{Context, SerenityError};
+
+const PERMISSIONS_L10N_KEYS: [(Permissions, &str); 8] = [
+    (Permissions::VIEW_CHANNEL, "red-alert-permission-view-channel"),
+    (Permissions::CONNECT, "red-alert-permission-connect"),
+    (Permissions::SEND_MESSAGES, "red-alert-permission-send-messages"),
+    (Permissions::MOVE_MEMBERS, "red-alert-permission-move-members"),
+    (Permissions::MUTE_MEMBERS, "red-alert-permission-mute-members"),
+    (Permissions::DEAFEN_MEMBERS, "red-alert-permission-deafen-members"),
+    (Permissions::MODERATE_MEMBERS, "red-alert-permission-moderate-members"),
+    (Permissions::MANAGE_ROLES, "red-alert-permission-manage-roles"),
+];
+
+pub const LISTEN_PERMISSIONS: Permissions = Permissions::VIEW_CHANNEL.union(Permissions::CONNECT);
+
+#[derive(Debug)]
+pub enum RedAlertPreflightError {
+    GuildNotCached,
+    ChannelNotFound,
+    MissingPermissions(Permissions),
+}
+
+/// Checks the bot permissions in the guild, or in the channel if it is given, using the cache.
+pub async fn preflight_permissions(
+    ctx: &Context,
+    guild_id: &GuildId,
+    channel_id: Option<&ChannelId>,
+    required_permissions: Permissions,
+) -> Result<(), RedAlertPreflightError> {
+    if required_permissions.is_empty() {
+        return Ok(());
+    }
+    let Some(guild) = ctx.cache.guild(guild_id) else {
+        return Err(RedAlertPreflightError::GuildNotCached);
+    };
+    let bot_user_id = ctx.cache.current_user_id();
+    let permissions = match channel_id {
+        Some(channel_id) => {
+            let Some(Channel::Guild(channel)) = guild.channels.get(channel_id) else {
+                return Err(RedAlertPreflightError::ChannelNotFound);
+            };
+            let Some(member) = guild.members.get(&bot_user_id) else {
+                return Err(RedAlertPreflightError::GuildNotCached);
+            };
+            guild.user_permissions_in(channel, member)
+        }
+        None => guild.member_permissions(ctx, bot_user_id).await,
+    };
+    let Ok(permissions) = permissions else {
+        return Err(RedAlertPreflightError::GuildNotCached);
+    };
+    let missing_permissions = required_permissions & !permissions;
+    if missing_permissions.is_empty() {
+        Ok(())
+    } else {
+        Err(RedAlertPreflightError::MissingPermissions(
+            missing_permissions,
+        ))
+    }
+}
+
+/// Permissions needed for every punishment the guild configuration can apply.
+pub fn guild_required_permissions(guild_voice_config: &RedAlertVoiceConfig<u64>) -> Permissions {
+    let rules_options = guild_voice_config
+        .self_words
+        .iter()
+        .map(|voice_phrase| &voice_phrase.options)
+        .chain(
+            guild_voice_config
+                .target_words
+                .iter()
+                .map(|voice_phrase| &voice_phrase.options),
+        )
+        .chain(guild_voice_config.aliases.values().map(|alias| &alias.options));
+    rules_options
+        .filter_map(|options| options.action.as_ref())
+        .chain(guild_voice_config.escalation.iter().flat_map(|escalation| &escalation.steps))
+        .chain([&guild_voice_config.action])
+        .fold(LISTEN_PERMISSIONS, |permissions, action| {
+            permissions | action.required_permissions()
+        })
+}
+
+pub fn permissions_description(l10n: &L10n, permissions: Permissions) -> String {
+    let mut described_permissions = Permissions::empty();
+    let mut names: Vec<String> = vec![];
+    for (permission, l10n_key) in PERMISSIONS_L10N_KEYS {
+        if permissions.contains(permission) {
+            names.push(l10n.string(l10n_key, fluent_args![]));
+            described_permissions |= permission;
+        }
+    }
+    for name in (permissions & !described_permissions).get_permission_names() {
+        names.push(name.to_owned());
+    }
+    names.join(", ")
+}
+
+pub fn preflight_error_description(l10n: &L10n, error: &RedAlertPreflightError) -> String {
+    match error {
+        RedAlertPreflightError::GuildNotCached => {
+            l10n.string("red-alert-preflight-guild-not-cached", fluent_args![])
+        }
+        RedAlertPreflightError::ChannelNotFound => {
+            l10n.string("red-alert-preflight-channel-not-found", fluent_args![])
+        }
+        RedAlertPreflightError::MissingPermissions(permissions) => l10n.string(
+            "red-alert-preflight-missing-permissions",
+            fluent_args![
+                "permissions" => permissions_description(l10n, *permissions)
+            ],
+        ),
+    }
+}
+
+/// Localizes the missing permissions of a failed punishment, other errors stay as they are.
+pub fn deportation_error_description(l10n: &L10n, error: &SerenityError) -> String {
+    match error {
+        SerenityError::Model(ModelError::InvalidPermissions(permissions)) => l10n.string(
+            "red-alert-preflight-missing-permissions",
+            fluent_args![
+                "permissions" => permissions_description(l10n, *permissions)
+            ],
+        ),
+        _ => error.to_string(),
+    }
+}
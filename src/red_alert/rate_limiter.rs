@@ -0,0 +1,296 @@
+use super::super::components::*;
+use serde::{Deserialize, Serialize};
+use serenity::model::id::GuildId;
+use serenity::model::prelude::UserId;
+use std::collections::{HashMap, VecDeque};
+use std::time::{Duration, Instant};
+
+const GUILD_RATE_PERIOD: Duration = Duration::from_secs(60);
+
+/// Zero disables the corresponding limit.
+#[derive(Clone, Debug, Default, PartialEq, Serialize, Deserialize)]
+pub struct RedAlertCooldowns {
+    #[serde(default)]
+    pub target_seconds: u64,
+    #[serde(default)]
+    pub author_seconds: u64,
+    #[serde(default)]
+    pub guild_kicks_per_minute: usize,
+}
+
+#[derive(Clone, Copy, Debug)]
+pub enum RedAlertRateLimitKind {
+    Target,
+    Author,
+    Guild,
+}
+
+#[derive(Clone, Copy, Debug)]
+pub struct RedAlertRateLimit {
+    pub kind: RedAlertRateLimitKind,
+    pub remaining: Duration,
+}
+
+impl RedAlertRateLimit {
+    pub fn description(&self, l10n: &L10n) -> String {
+        let remaining_seconds = self.remaining.as_secs() + 1;
+        match self.kind {
+            RedAlertRateLimitKind::Target => l10n.string(
+                "red-alert-rate-limit-target",
+                fluent_args![
+                    "seconds" => remaining_seconds
+                ],
+            ),
+            RedAlertRateLimitKind::Author => l10n.string(
+                "red-alert-rate-limit-author",
+                fluent_args![
+                    "seconds" => remaining_seconds
+                ],
+            ),
+            RedAlertRateLimitKind::Guild => l10n.string(
+                "red-alert-rate-limit-guild",
+                fluent_args![
+                    "seconds" => remaining_seconds
+                ],
+            ),
+        }
+    }
+}
+
+/// Author cooldown taken by one red alert, kept only if one of its targets was punished.
+struct RedAlertAuthorCooldown {
+    taken_at: Instant,
+    pending_count: usize,
+    is_used: bool,
+}
+
+#[derive(Default)]
+struct RedAlertGuildRateLimiter {
+    targets: HashMap<UserId, Instant>,
+    authors: HashMap<UserId, RedAlertAuthorCooldown>,
+    kicks: VecDeque<Instant>,
+}
+
+fn cooldown_remaining(last_time: Option<&Instant>, seconds: u64) -> Option<Duration> {
+    let cooldown = Duration::from_secs(seconds);
+    let elapsed = last_time?.elapsed();
+    if elapsed < cooldown {
+        Some(cooldown - elapsed)
+    } else {
+        None
+    }
+}
+
+/// Reserved place in the limits, finished when the punishment is done or abandoned.
+#[derive(Clone, Copy, Debug)]
+pub struct RedAlertRateLimitSlot {
+    guild_id: GuildId,
+    user_id: UserId,
+    taken_at: Instant,
+}
+
+#[derive(Clone, Copy, Debug)]
+pub struct RedAlertTargetSlot {
+    target: RedAlertRateLimitSlot,
+    author: Option<RedAlertRateLimitSlot>,
+}
+
+#[derive(Default)]
+pub struct RedAlertRateLimiter(HashMap<GuildId, RedAlertGuildRateLimiter>);
+
+impl RedAlertRateLimiter {
+    /// Takes the author cooldown for one red alert, whatever number of targets it has.
+    /// The slot must be finished with `finish_author` once every target was acquired.
+    pub fn acquire_author(
+        &mut self,
+        guild_id: GuildId,
+        author_id: UserId,
+        cooldowns: &RedAlertCooldowns,
+    ) -> Result<RedAlertRateLimitSlot, RedAlertRateLimit> {
+        let guild_rate_limiter = self.0.entry(guild_id).or_default();
+        if let Some(remaining) = cooldown_remaining(
+            guild_rate_limiter
+                .authors
+                .get(&author_id)
+                .map(|author_cooldown| &author_cooldown.taken_at),
+            cooldowns.author_seconds,
+        ) {
+            return Err(RedAlertRateLimit {
+                kind: RedAlertRateLimitKind::Author,
+                remaining,
+            });
+        }
+        let taken_at = Instant::now();
+        if cooldowns.author_seconds > 0 {
+            guild_rate_limiter.authors.insert(
+                author_id,
+                RedAlertAuthorCooldown {
+                    taken_at,
+                    pending_count: 1,
+                    is_used: false,
+                },
+            );
+        }
+        Ok(RedAlertRateLimitSlot {
+            guild_id,
+            user_id: author_id,
+            taken_at,
+        })
+    }
+    /// Takes the target cooldown and a place in the guild kicks per minute.
+    /// The slot must be finished with `finish_target` when the punishment is done or abandoned.
+    pub fn acquire_target(
+        &mut self,
+        guild_id: GuildId,
+        target_id: UserId,
+        author_slot: Option<&RedAlertRateLimitSlot>,
+        cooldowns: &RedAlertCooldowns,
+    ) -> Result<RedAlertTargetSlot, RedAlertRateLimit> {
+        let guild_rate_limiter = self.0.entry(guild_id).or_default();
+        if let Some(remaining) = cooldown_remaining(
+            guild_rate_limiter.targets.get(&target_id),
+            cooldowns.target_seconds,
+        ) {
+            return Err(RedAlertRateLimit {
+                kind: RedAlertRateLimitKind::Target,
+                remaining,
+            });
+        }
+        while let Some(kick_time) = guild_rate_limiter.kicks.front() {
+            if kick_time.elapsed() < GUILD_RATE_PERIOD {
+                break;
+            }
+            guild_rate_limiter.kicks.pop_front();
+        }
+        if cooldowns.guild_kicks_per_minute > 0
+            && guild_rate_limiter.kicks.len() >= cooldowns.guild_kicks_per_minute
+        {
+            let oldest_kick_elapsed = guild_rate_limiter
+                .kicks
+                .front()
+                .map_or(GUILD_RATE_PERIOD, Instant::elapsed);
+            return Err(RedAlertRateLimit {
+                kind: RedAlertRateLimitKind::Guild,
+                remaining: GUILD_RATE_PERIOD.saturating_sub(oldest_kick_elapsed),
+            });
+        }
+        let taken_at = Instant::now();
+        if cooldowns.guild_kicks_per_minute > 0 {
+            guild_rate_limiter.kicks.push_back(taken_at);
+        }
+        if cooldowns.target_seconds > 0 {
+            guild_rate_limiter.targets.insert(target_id, taken_at);
+        }
+        if let Some(author_slot) = author_slot {
+            if let Some(author_cooldown) = guild_rate_limiter.author_cooldown(author_slot) {
+                author_cooldown.pending_count += 1;
+            }
+        }
+        Ok(RedAlertTargetSlot {
+            target: RedAlertRateLimitSlot {
+                guild_id,
+                user_id: target_id,
+                taken_at,
+            },
+            author: author_slot.copied(),
+        })
+    }
+    /// Keeps the target slot if the punishment was applied, releases it otherwise.
+    pub fn finish_target(&mut self, target_slot: RedAlertTargetSlot, is_punished: bool) {
+        let target = target_slot.target;
+        let Some(guild_rate_limiter) = self.0.get_mut(&target.guild_id) else {
+            return;
+        };
+        if !is_punished {
+            if guild_rate_limiter.targets.get(&target.user_id) == Some(&target.taken_at) {
+                guild_rate_limiter.targets.remove(&target.user_id);
+            }
+            if let Some(index) = guild_rate_limiter
+                .kicks
+                .iter()
+                .position(|kick_time| *kick_time == target.taken_at)
+            {
+                guild_rate_limiter.kicks.remove(index);
+            }
+        }
+        let Some(author_slot) = target_slot.author else {
+            return;
+        };
+        if let Some(author_cooldown) = guild_rate_limiter.author_cooldown(&author_slot) {
+            author_cooldown.is_used |= is_punished;
+        }
+        self.finish_author(author_slot);
+    }
+    /// Releases the author slot when all its targets are finished and none was punished.
+    pub fn finish_author(&mut self, author_slot: RedAlertRateLimitSlot) {
+        let Some(guild_rate_limiter) = self.0.get_mut(&author_slot.guild_id) else {
+            return;
+        };
+        let Some(author_cooldown) = guild_rate_limiter.author_cooldown(&author_slot) else {
+            return;
+        };
+        author_cooldown.pending_count = author_cooldown.pending_count.saturating_sub(1);
+        if author_cooldown.pending_count == 0 && !author_cooldown.is_used {
+            guild_rate_limiter.authors.remove(&author_slot.user_id);
+        }
+    }
+}
+
+impl RedAlertGuildRateLimiter {
+    fn author_cooldown(
+        &mut self,
+        author_slot: &RedAlertRateLimitSlot,
+    ) -> Option<&mut RedAlertAuthorCooldown> {
+        self.authors
+            .get_mut(&author_slot.user_id)
+            .filter(|author_cooldown| author_cooldown.taken_at == author_slot.taken_at)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn slots_are_reserved_until_finished() {
+        let cooldowns = RedAlertCooldowns {
+            target_seconds: 60,
+            author_seconds: 60,
+            guild_kicks_per_minute: 1,
+        };
+        let guild_id = GuildId(1);
+        let author_id = UserId(2);
+        let mut rate_limiter = RedAlertRateLimiter::default();
+        let author_slot = rate_limiter
+            .acquire_author(guild_id, author_id, &cooldowns)
+            .unwrap();
+        let target_slot = rate_limiter
+            .acquire_target(guild_id, UserId(3), Some(&author_slot), &cooldowns)
+            .unwrap();
+        assert!(rate_limiter
+            .acquire_target(guild_id, UserId(3), Some(&author_slot), &cooldowns)
+            .is_err());
+        assert!(rate_limiter
+            .acquire_target(guild_id, UserId(4), Some(&author_slot), &cooldowns)
+            .is_err());
+        rate_limiter.finish_author(author_slot);
+        assert!(rate_limiter
+            .acquire_author(guild_id, author_id, &cooldowns)
+            .is_err());
+        rate_limiter.finish_target(target_slot, false);
+        let author_slot = rate_limiter
+            .acquire_author(guild_id, author_id, &cooldowns)
+            .unwrap();
+        let target_slot = rate_limiter
+            .acquire_target(guild_id, UserId(3), Some(&author_slot), &cooldowns)
+            .unwrap();
+        rate_limiter.finish_author(author_slot);
+        rate_limiter.finish_target(target_slot, true);
+        assert!(rate_limiter
+            .acquire_author(guild_id, author_id, &cooldowns)
+            .is_err());
+        assert!(rate_limiter
+            .acquire_target(guild_id, UserId(3), None, &cooldowns)
+            .is_err());
+    }
+}
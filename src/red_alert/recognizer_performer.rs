@@ -1,7 +1,7 @@
 use super::super::components::*;
 use super::*;
 use serenity::model::id::GuildId;
-use serenity::model::prelude::UserId;
+use serenity::model::prelude::{RoleId, UserId};
 use serenity::prelude::Context;
 use std::collections::{HashMap, HashSet};
 use std::sync::Arc;
@@ -12,9 +12,14 @@ use voskrust::api::Model as VoskModel;
 pub struct RedAlertRecognizerPerformer {
     pub guilds_voices_receivers: Arc<RwLock<HashMap<GuildId, VoiceReceiver>>>,
     pub actions_history: Arc<Mutex<RedAlertActionsHistory>>,
+    pub near_misses_history: Arc<Mutex<RedAlertNearMissesHistory>>,
+    pub rate_limiter: Arc<Mutex<RedAlertRateLimiter>>,
+    pub pending_actions: Arc<Mutex<RedAlertPendingActions>>,
+    pub siren_player: Arc<RedAlertSirenPlayer>,
     pub recognition_model: VoskModel,
     pub guilds_voice_config: Arc<RwLock<RedAlertGuildsVoiceConfig>>,
     pub red_alert_handler: Arc<RedAlertHandler>,
+    pub l10n: L10n,
 }
 
 impl RedAlertRecognizerPerformer {
@@ -22,9 +27,14 @@ impl RedAlertRecognizerPerformer {
         let (tx, mut rx) = channel::<()>();
         let guilds_voices_receivers = self.guilds_voices_receivers.clone();
         let actions_history = self.actions_history.clone();
+        let near_misses_history = self.near_misses_history.clone();
+        let rate_limiter = self.rate_limiter.clone();
+        let pending_actions = self.pending_actions.clone();
+        let siren_player = self.siren_player.clone();
         let recognition_model = self.recognition_model.clone();
         let guilds_voice_config = self.guilds_voice_config.clone();
         let red_alert_handler = self.red_alert_handler.clone();
+        let l10n = self.l10n.clone();
         let ctx = ctx.clone();
         tokio::spawn(async move {
             let mut recognizer_signal = Recognizer {
@@ -70,14 +80,55 @@ impl RedAlertRecognizerPerformer {
                             "{} Recognition RESULT: type: {:?}, text: \"{}\".",
                             log_prefix, result.result_type, result.text
                         );
-                        let guilds_voice_config = guilds_voice_config.read().await;
-                        let users_ids_kicks_reasons = guilds_voice_config
-                            .get(&info.guild_id)
-                            .should_kick(&info.user_id.0, &result.text)
-                            .into_iter()
-                            .map(|v| (UserId(*v.0), v.1))
-                            .collect::<HashMap<UserId, RedAlertVoiceSearchResult>>();
-                        drop(guilds_voice_config);
+                        let match_index = guilds_voice_config
+                            .read()
+                            .await
+                            .match_index(&info.guild_id);
+                        if match_index
+                            .config()
+                            .countdown
+                            .is_cancel_phrase(&match_index.config().normalization, &result.text)
+                            && pending_actions.lock().await.cancel(
+                                &info.guild_id,
+                                &info.user_id,
+                                RedAlertCancelReason::Phrase,
+                            )
+                        {
+                            info!(
+                                "{} Recognition RESULT cancel phrase, pending punishment cancelled.",
+                                log_prefix
+                            );
+                        }
+                        let near_miss_margin = match result.result_type {
+                            RecognitionResultType::Final => match_index.config().near_miss_margin,
+                            RecognitionResultType::Partial => 0.0,
+                        };
+                        let rules_reports = match_index.rules_reports(
+                            &info.user_id.0,
+                            &result.text,
+                            near_miss_margin,
+                        );
+                        for rule_report in &rules_reports {
+                            if rule_report.search_result.is_some() {
+                                continue;
+                            }
+                            info!(
+                                "{} Recognition RESULT near miss. Have \"{}\"({}) below threshold {}.",
+                                log_prefix,
+                                rule_report.word,
+                                rule_report.similarity(),
+                                rule_report.similarity_threshold()
+                            );
+                            near_misses_history.lock().await.log_near_miss(
+                                info.guild_id,
+                                RedAlertNearMiss::new(info.user_id, result.text.clone(), rule_report),
+                            );
+                        }
+                        let users_ids_kicks_reasons = resolve_voice_targets(
+                            &ctx,
+                            &info.guild_id,
+                            RedAlertVoiceMatchIndex::kicks_targets(rules_reports),
+                        );
                         let mut users_ids_kicks = users_ids_kicks_reasons
                             .keys()
                             .cloned()
@@ -94,43 +145,248 @@ impl RedAlertRecognizerPerformer {
                         if users_ids_kicks.is_empty() {
                             continue;
                         };
+                        let cooldowns = &match_index.config().cooldowns;
+                        let mut author_rate_limit: Option<
+                            Result<RedAlertRateLimitSlot, RedAlertRateLimit>,
+                        > = None;
                         for (kick_user_id, kick_reason) in users_ids_kicks_reasons {
                             if !users_ids_kicks.contains(&kick_user_id) {
                                 continue;
                             }
+                            if let Some(exception) = &kick_reason.suppressed_by {
+                                info!(
+                                    "{} Recognition RESULT kick suppressed. Have restriction \"{}\"({}) =~ \"{}\" overlapped by exception \"{}\".",
+                                    log_prefix,
+                                    kick_reason.real_word,
+                                    kick_reason.total_similarity,
+                                    kick_reason.word,
+                                    exception
+                                );
+                                actions_history.lock().await.log_history(
+                                    info.guild_id,
+                                    RedAlertActionType::VoiceSuppressed {
+                                        author_id: info.user_id,
+                                        target_id: kick_user_id,
+                                        full_text: result.text.clone(),
+                                        reason: kick_reason,
+                                    },
+                                );
+                                continue;
+                            }
+                            let rate_limit_slot = {
+                                let mut rate_limiter = rate_limiter.lock().await;
+                                let author_rate_limit = if kick_user_id == info.user_id {
+                                    Ok(None)
+                                } else {
+                                    (*author_rate_limit.get_or_insert_with(|| {
+                                        rate_limiter.acquire_author(
+                                            info.guild_id,
+                                            info.user_id,
+                                            cooldowns,
+                                        )
+                                    }))
+                                    .map(Some)
+                                };
+                                author_rate_limit.and_then(|author_slot| {
+                                    rate_limiter.acquire_target(
+                                        info.guild_id,
+                                        kick_user_id,
+                                        author_slot.as_ref(),
+                                        cooldowns,
+                                    )
+                                })
+                            };
+                            let rate_limit_slot = match rate_limit_slot {
+                                Ok(rate_limit_slot) => rate_limit_slot,
+                                Err(limit) => {
+                                    info!(
+                                        "{} Recognition RESULT kick rate limited. Have restriction \"{}\" =~ \"{}\" limited by {:?}.",
+                                        log_prefix, kick_reason.real_word, kick_reason.word, limit
+                                    );
+                                    actions_history.lock().await.log_history(
+                                        info.guild_id,
+                                        RedAlertActionType::RateLimited {
+                                            author_id: info.user_id,
+                                            target_id: kick_user_id,
+                                            limit,
+                                        },
+                                    );
+                                    continue;
+                                }
+                            };
                             info!(
-                                "{} Recognition RESULT will be used for kick. Have restriction \"{}\"({}) =~ \"{}\".",
+                                "{} Recognition RESULT will be used for {}. Have restriction \"{}\"({}) =~ \"{}\" at words {:?}.",
                                 log_prefix,
+                                kick_reason.action.name(),
                                 kick_reason.real_word,
                                 kick_reason.total_similarity,
-                                kick_reason.word
+                                kick_reason.word,
+                                kick_reason.span
                             );
+                            let match_index = match_index.clone();
                             let actions_history = actions_history.clone();
                             let red_alert_handler = red_alert_handler.clone();
+                            let rate_limiter = rate_limiter.clone();
+                            let pending_actions = pending_actions.clone();
+                            let siren_player = siren_player.clone();
+                            let l10n = l10n.clone();
                             let ctx = ctx.clone();
                             let log_prefix = log_prefix.clone();
                             let result_text = result.text.clone();
                             tokio::spawn(async move {
                                 let guild_id = info.guild_id;
+                                let mut kick_reason = kick_reason;
+                                if let Some(vote_config) = &match_index.config().vote {
+                                    let vote_outcome = community_vote(
+                                        &ctx,
+                                        &l10n,
+                                        &guild_id,
+                                        &kick_user_id,
+                                        &result_text,
+                                        &kick_reason,
+                                        vote_config,
+                                    )
+                                    .await;
+                                    info!(
+                                        "{} Recognition RESULT vote for kick, outcome is {:?}.",
+                                        log_prefix, vote_outcome
+                                    );
+                                    actions_history.lock().await.log_history(
+                                        guild_id,
+                                        RedAlertActionType::Vote {
+                                            author_id: info.user_id,
+                                            target_id: kick_user_id,
+                                            full_text: result_text.clone(),
+                                            outcome: vote_outcome,
+                                        },
+                                    );
+                                    if !vote_outcome.is_confirmed() {
+                                        rate_limiter
+                                            .lock()
+                                            .await
+                                            .finish_target(rate_limit_slot, false);
+                                        return;
+                                    }
+                                }
+                                if let Some(siren_path) = &kick_reason.siren {
+                                    if let Err(error) = siren_player
+                                        .play(
+                                            &ctx,
+                                            guild_id,
+                                            siren_path,
+                                            match_index.config().siren.volume,
+                                        )
+                                        .await
+                                    {
+                                        warn!(
+                                            "{} Recognition RESULT siren \"{}\" playing error: {:?}.",
+                                            log_prefix, siren_path, error
+                                        );
+                                    }
+                                }
+                                if match_index.config().countdown.seconds > 0 {
+                                    let Some(cancel_receiver) =
+                                        pending_actions.lock().await.schedule(
+                                            guild_id,
+                                            kick_user_id,
+                                            info.user_id,
+                                            red_alert_handler
+                                                .escalated_action(
+                                                    &guild_id,
+                                                    &kick_user_id,
+                                                    &kick_reason.action,
+                                                    match_index.config(),
+                                                )
+                                                .await,
+                                            match_index.config().countdown.delay(),
+                                        )
+                                    else {
+                                        info!(
+                                            "{} Recognition RESULT kick skipped, punishment is already pending.",
+                                            log_prefix
+                                        );
+                                        rate_limiter
+                                            .lock()
+                                            .await
+                                            .finish_target(rate_limit_slot, false);
+                                        return;
+                                    };
+                                    if let Some(cancel_reason) = countdown(
+                                        &ctx,
+                                        &l10n,
+                                        &pending_actions,
+                                        &guild_id,
+                                        &kick_user_id,
+                                        cancel_receiver,
+                                        match_index.config(),
+                                    )
+                                    .await
+                                    {
+                                        info!(
+                                            "{} Recognition RESULT kick cancelled by {:?}.",
+                                            log_prefix, cancel_reason
+                                        );
+                                        actions_history.lock().await.log_history(
+                                            guild_id,
+                                            RedAlertActionType::Cancelled {
+                                                author_id: info.user_id,
+                                                target_id: kick_user_id,
+                                                reason: cancel_reason,
+                                            },
+                                        );
+                                        rate_limiter
+                                            .lock()
+                                            .await
+                                            .finish_target(rate_limit_slot, false);
+                                        return;
+                                    }
+                                }
                                 let deportation_result = red_alert_handler
-                                    .single(&ctx, &guild_id, &kick_user_id)
+                                    .single(
+                                        &ctx,
+                                        &guild_id,
+                                        &kick_user_id,
+                                        &kick_reason.action,
+                                        &RedAlertTrigger::Voice {
+                                            author_id: info.user_id,
+                                            phrase: kick_reason.word.clone(),
+                                        },
+                                        match_index.config(),
+                                    )
                                     .await;
+                                if let Some(action) = deportation_result.action() {
+                                    kick_reason.action = action.clone();
+                                }
+                                rate_limiter.lock().await.finish_target(
+                                    rate_limit_slot,
+                                    deportation_result.is_deported(),
+                                );
                                 info!(
                                     "{} Recognition RESULT used for kick, status is {:?}.",
                                     log_prefix, deportation_result
                                 );
                                 actions_history.lock().await.log_history(
                                     guild_id,
-                                    RedAlertActionType::Voice {
-                                        author_id: info.user_id,
-                                        target_id: kick_user_id,
-                                        full_text: result_text,
-                                        reason: kick_reason,
-                                        is_success: deportation_result.is_deported(),
+                                    if deportation_result.is_immune() {
+                                        RedAlertActionType::Immune {
+                                            author_id: info.user_id,
+                                            target_id: kick_user_id,
+                                        }
+                                    } else {
+                                        RedAlertActionType::Voice {
+                                            author_id: info.user_id,
+                                            target_id: kick_user_id,
+                                            full_text: result_text,
+                                            reason: kick_reason,
+                                            is_success: deportation_result.is_deported(),
+                                        }
                                     },
                                 );
                             });
                         }
+                        if let Some(Ok(author_slot)) = author_rate_limit {
+                            rate_limiter.lock().await.finish_author(author_slot);
+                        }
                     }
                     RecognizerState::RecognitionStart(info) => {
                         info!("{} Recognition STARTED.", log_prefix);
@@ -146,3 +402,47 @@ impl RedAlertRecognizerPerformer {
         tx
     }
 }
+
+fn resolve_voice_targets(
+    ctx: &Context,
+    guild_id: &GuildId,
+    targets: HashMap<RedAlertVoiceTarget<&u64>, RedAlertVoiceSearchResult>,
+) -> HashMap<UserId, RedAlertVoiceSearchResult> {
+    let mut users_ids_kicks_reasons = HashMap::new();
+    let mut roles_kicks_reasons = vec![];
+    for (target, kick_reason) in targets {
+        match target {
+            RedAlertVoiceTarget::User(user_id) => {
+                users_ids_kicks_reasons.insert(UserId(*user_id), kick_reason);
+            }
+            RedAlertVoiceTarget::Role(role_id) => {
+                roles_kicks_reasons.push((RoleId(*role_id), kick_reason));
+            }
+        }
+    }
+    if roles_kicks_reasons.is_empty() {
+        return users_ids_kicks_reasons;
+    }
+    let Some(guild) = ctx.cache.guild(guild_id) else {
+        return users_ids_kicks_reasons;
+    };
+    let bot_user_id = ctx.cache.current_user_id();
+    for (user_id, voice_state) in &guild.voice_states {
+        if *user_id == bot_user_id || voice_state.channel_id.is_none() {
+            continue;
+        }
+        let Some(member) = guild.members.get(user_id).or(voice_state.member.as_ref()) else {
+            continue;
+        };
+        for (role_id, kick_reason) in &roles_kicks_reasons {
+            if !member.roles.contains(role_id) {
+                continue;
+            }
+            users_ids_kicks_reasons
+                .entry(*user_id)
+                .or_insert_with(|| kick_reason.clone());
+            break;
+        }
+    }
+    users_ids_kicks_reasons
+}
@@ -0,0 +1,70 @@
+use rand::Rng;
+use serde::{Deserialize, Serialize};
+use serenity::http::HttpError;
+use serenity::prelude::SerenityError;
+use std::time::Duration;
+
+const TARGET_NOT_CONNECTED_ERROR_CODE: isize = 40032;
+const TOO_MANY_REQUESTS_STATUS: u16 = 429;
+
+/// One attempt disables retries.
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct RedAlertRetryPolicy {
+    pub max_attempts: u32,
+    pub base_delay_ms: u64,
+    pub max_delay_ms: u64,
+}
+
+impl Default for RedAlertRetryPolicy {
+    fn default() -> Self {
+        Self {
+            max_attempts: 1,
+            base_delay_ms: 500,
+            max_delay_ms: 8000,
+        }
+    }
+}
+
+impl RedAlertRetryPolicy {
+    /// Exponential backoff after the failed attempt, randomly shortened by up to a half.
+    pub fn delay(&self, attempt: u32) -> Duration {
+        let backoff_ms = self
+            .base_delay_ms
+            .saturating_mul(1 << attempt.saturating_sub(1).min(16))
+            .min(self.max_delay_ms);
+        let jitter_ms = match backoff_ms / 2 {
+            0 => 0,
+            max_jitter_ms => rand::thread_rng().gen_range(0..=max_jitter_ms),
+        };
+        Duration::from_millis(backoff_ms - jitter_ms)
+    }
+}
+
+/// Server errors, rate limits and failed connections can pass on the next attempt.
+pub fn is_retryable(error: &SerenityError) -> bool {
+    let SerenityError::Http(http_error) = error else {
+        return false;
+    };
+    match http_error.as_ref() {
+        HttpError::UnsuccessfulRequest(error_response) => {
+            error_response.status_code.is_server_error()
+                || error_response.status_code.as_u16() == TOO_MANY_REQUESTS_STATUS
+        }
+        HttpError::Request(_) => true,
+        _ => false,
+    }
+}
+
+/// Discord rejects voice actions on users which left the voice channel after the lookup.
+pub fn is_target_not_connected(error: &SerenityError) -> bool {
+    let SerenityError::Http(http_error) = error else {
+        return false;
+    };
+    match http_error.as_ref() {
+        HttpError::UnsuccessfulRequest(error_response) => {
+            error_response.error.code == TARGET_NOT_CONNECTED_ERROR_CODE
+        }
+        _ => false,
+    }
+}
@@ -0,0 +1,204 @@
+use serde::{Deserialize, Serialize};
+use serenity::model::id::GuildId;
+use serenity::prelude::Context;
+use songbird::{Call, Event, EventContext, EventHandler as VoiceEventHandler, TrackEvent};
+use std::collections::HashMap;
+use std::path::{Component, Path, PathBuf};
+use std::sync::Arc;
+use tokio::sync::Mutex;
+
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct RedAlertSiren {
+    pub path: Option<String>,
+    pub volume: f32,
+}
+
+impl Default for RedAlertSiren {
+    fn default() -> Self {
+        Self {
+            path: None,
+            volume: 1.0,
+        }
+    }
+}
+
+#[derive(Debug)]
+pub enum RedAlertSirenError {
+    SirensDisabled,
+    ForbiddenPath,
+    FileNotFound,
+    SongbirdMissing,
+    NotConnected,
+    SourceError,
+    TrackError,
+}
+
+/// Plays sirens through the call queue, so overlapping alerts wait for each other.
+/// Only files inside the operator's sirens directory are played.
+#[derive(Default)]
+pub struct RedAlertSirenPlayer {
+    queued_sirens: Arc<Mutex<HashMap<GuildId, usize>>>,
+    sirens_path: Option<PathBuf>,
+}
+
+impl RedAlertSirenPlayer {
+    pub fn new(sirens_path: Option<PathBuf>) -> Self {
+        Self {
+            queued_sirens: Default::default(),
+            sirens_path,
+        }
+    }
+    /// Resolves a siren file name configured by a guild to an existing file inside the sirens
+    /// directory, absolute paths, `..` and URLs are rejected.
+    pub fn resolve(&self, name: &str) -> Result<PathBuf, RedAlertSirenError> {
+        let Some(sirens_path) = &self.sirens_path else {
+            return Err(RedAlertSirenError::SirensDisabled);
+        };
+        let is_plain_relative = Path::new(name)
+            .components()
+            .all(|component| matches!(component, Component::Normal(_)));
+        if name.is_empty() || name.contains("://") || !is_plain_relative {
+            return Err(RedAlertSirenError::ForbiddenPath);
+        }
+        let Ok(sirens_path) = sirens_path.canonicalize() else {
+            return Err(RedAlertSirenError::SirensDisabled);
+        };
+        let Ok(path) = sirens_path.join(name).canonicalize() else {
+            return Err(RedAlertSirenError::FileNotFound);
+        };
+        if !path.starts_with(&sirens_path) {
+            return Err(RedAlertSirenError::ForbiddenPath);
+        }
+        if !path.is_file() {
+            return Err(RedAlertSirenError::FileNotFound);
+        }
+        Ok(path)
+    }
+    pub async fn play(
+        &self,
+        ctx: &Context,
+        guild_id: GuildId,
+        name: &str,
+        volume: f32,
+    ) -> Result<(), RedAlertSirenError> {
+        let path = self.resolve(name)?;
+        let Some(manager) = songbird::get(ctx).await else {
+            return Err(RedAlertSirenError::SongbirdMissing);
+        };
+        let Some(call_lock) = manager.get(guild_id) else {
+            return Err(RedAlertSirenError::NotConnected);
+        };
+        let Ok(source) = songbird::ffmpeg(&path).await else {
+            return Err(RedAlertSirenError::SourceError);
+        };
+        let mut call = call_lock.lock().await;
+        *self.queued_sirens.lock().await.entry(guild_id).or_default() += 1;
+        let track_handle = call.enqueue_source(source);
+        _ = track_handle.set_volume(volume);
+        if track_handle
+            .add_event(
+                Event::Track(TrackEvent::End),
+                RedAlertSirenEndNotifier {
+                    guild_id,
+                    call_lock: call_lock.clone(),
+                    queued_sirens: self.queued_sirens.clone(),
+                },
+            )
+            .is_err()
+        {
+            _ = track_handle.stop();
+            release_siren(&self.queued_sirens, &guild_id).await;
+            return Err(RedAlertSirenError::TrackError);
+        }
+        _ = call.mute(false).await;
+        Ok(())
+    }
+}
+
+/// Returns true if it was the last queued siren of the guild.
+async fn release_siren(
+    queued_sirens: &Arc<Mutex<HashMap<GuildId, usize>>>,
+    guild_id: &GuildId,
+) -> bool {
+    let mut queued_sirens = queued_sirens.lock().await;
+    let Some(guild_queued_sirens) = queued_sirens.get_mut(guild_id) else {
+        return true;
+    };
+    *guild_queued_sirens = guild_queued_sirens.saturating_sub(1);
+    if *guild_queued_sirens > 0 {
+        return false;
+    }
+    queued_sirens.remove(guild_id);
+    true
+}
+
+struct RedAlertSirenEndNotifier {
+    guild_id: GuildId,
+    call_lock: Arc<Mutex<Call>>,
+    queued_sirens: Arc<Mutex<HashMap<GuildId, usize>>>,
+}
+
+#[async_trait]
+impl VoiceEventHandler for RedAlertSirenEndNotifier {
+    async fn act(&self, _ctx: &EventContext<'_>) -> Option<Event> {
+        if release_siren(&self.queued_sirens, &self.guild_id).await {
+            _ = self.call_lock.lock().await.mute(true).await;
+        }
+        None
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::fs;
+
+    #[test]
+    fn resolve_accepts_only_files_inside_sirens_path() {
+        let root_path =
+            std::env::temp_dir().join(format!("red-alert-sirens-{}", std::process::id()));
+        let sirens_path = root_path.join("sirens");
+        fs::create_dir_all(sirens_path.join("loud")).unwrap();
+        fs::write(sirens_path.join("siren.mp3"), b"").unwrap();
+        fs::write(sirens_path.join("loud").join("siren.mp3"), b"").unwrap();
+        fs::write(root_path.join("secret.txt"), b"").unwrap();
+        let siren_player = RedAlertSirenPlayer::new(Some(sirens_path.clone()));
+        assert!(siren_player.resolve("siren.mp3").is_ok());
+        assert!(siren_player.resolve("loud/siren.mp3").is_ok());
+        for name in [
+            "../secret.txt",
+            "loud/../../secret.txt",
+            "./siren.mp3",
+            "https://example.com/siren.mp3",
+            "",
+        ] {
+            assert!(
+                matches!(
+                    siren_player.resolve(name),
+                    Err(RedAlertSirenError::ForbiddenPath)
+                ),
+                "{}",
+                name
+            );
+        }
+        let absolute_name = root_path.join("secret.txt");
+        assert!(matches!(
+            siren_player.resolve(&absolute_name.to_string_lossy()),
+            Err(RedAlertSirenError::ForbiddenPath)
+        ));
+        assert!(matches!(
+            siren_player.resolve("missing.mp3"),
+            Err(RedAlertSirenError::FileNotFound)
+        ));
+        assert!(matches!(
+            siren_player.resolve("loud"),
+            Err(RedAlertSirenError::FileNotFound)
+        ));
+        assert!(matches!(
+            RedAlertSirenPlayer::new(None).resolve("siren.mp3"),
+            Err(RedAlertSirenError::SirensDisabled)
+        ));
+        fs::remove_dir_all(root_path).unwrap();
+    }
+}
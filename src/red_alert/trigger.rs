@@ -0,0 +1,49 @@
+use super::super::components::*;
+use serenity::model::prelude::UserId;
+
+/// Discord rejects audit log reasons longer than this count of characters.
+const AUDIT_LOG_REASON_MAX_LENGTH: usize = 512;
+/// Fluent wraps placeables with these, the audit log shows them as garbage and they take the length.
+const BIDI_ISOLATION_MARKS: [char; 2] = ['\u{2068}', '\u{2069}'];
+
+/// What caused a punishment, used to explain it outside of the bot.
+#[derive(Clone, Debug)]
+pub enum RedAlertTrigger {
+    Voice { author_id: UserId, phrase: String },
+    Text { author_id: UserId },
+}
+
+impl RedAlertTrigger {
+    pub fn author_id(&self) -> UserId {
+        match self {
+            RedAlertTrigger::Voice { author_id, .. } | RedAlertTrigger::Text { author_id } => {
+                *author_id
+            }
+        }
+    }
+    /// The author name is the user tag, since mentions aren't rendered in the audit log.
+    pub fn audit_log_reason(&self, l10n: &L10n, author_name: &str) -> String {
+        let reason = match self {
+            RedAlertTrigger::Voice { author_id, phrase } => l10n.string(
+                "red-alert-audit-log-reason-voice",
+                fluent_args![
+                    "author-name" => author_name,
+                    "author-id" => author_id.to_string(),
+                    "phrase" => phrase.as_str()
+                ],
+            ),
+            RedAlertTrigger::Text { author_id } => l10n.string(
+                "red-alert-audit-log-reason-text",
+                fluent_args![
+                    "author-name" => author_name,
+                    "author-id" => author_id.to_string()
+                ],
+            ),
+        };
+        reason
+            .chars()
+            .filter(|letter| !BIDI_ISOLATION_MARKS.contains(letter))
+            .take(AUDIT_LOG_REASON_MAX_LENGTH)
+            .collect()
+    }
+}
@@ -0,0 +1,295 @@
+use super::super::components::*;
+use super::*;
+use serde::{Deserialize, Serialize};
+use std::collections::HashSet;
+use std::hash::Hash;
+
+#[derive(Clone, Debug, Default, PartialEq, Serialize, Deserialize)]
+pub struct RedAlertVoiceMatchOptions {
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub similarity_threshold: Option<f32>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub matcher: Option<PhraseMatcherKind>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub phonetic_weight: Option<f32>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub action: Option<RedAlertActionKind>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub siren: Option<String>,
+}
+
+impl RedAlertVoiceMatchOptions {
+    pub fn is_empty(&self) -> bool {
+        self == &Self::default()
+    }
+    /// Fills options missed in `self` with values from `other`.
+    pub fn or(&self, other: &Self) -> Self {
+        Self {
+            similarity_threshold: self.similarity_threshold.or(other.similarity_threshold),
+            matcher: self.matcher.or(other.matcher),
+            phonetic_weight: self.phonetic_weight.or(other.phonetic_weight),
+            action: self.action.clone().or_else(|| other.action.clone()),
+            siren: self.siren.clone().or_else(|| other.siren.clone()),
+        }
+    }
+}
+
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+#[serde(
+    try_from = "RedAlertVoicePhraseEntry",
+    into = "RedAlertVoicePhraseEntry"
+)]
+pub struct RedAlertVoicePhrase {
+    pub phrase: SearchPhrase,
+    pub options: RedAlertVoiceMatchOptions,
+}
+
+impl RedAlertVoicePhrase {
+    pub fn new(phrase: SearchPhrase) -> Self {
+        Self {
+            phrase,
+            options: Default::default(),
+        }
+    }
+}
+
+/// Phrases are kept as text here and compiled after the variant is picked,
+/// otherwise an invalid pattern is reported as "data did not match any variant".
+#[derive(Serialize, Deserialize)]
+#[serde(untagged)]
+pub enum RedAlertVoicePhraseEntry {
+    Plain(String),
+    Detailed {
+        phrase: String,
+        #[serde(flatten)]
+        options: RedAlertVoiceMatchOptions,
+    },
+}
+
+impl TryFrom<RedAlertVoicePhraseEntry> for RedAlertVoicePhrase {
+    type Error = String;
+    fn try_from(entry: RedAlertVoicePhraseEntry) -> Result<Self, Self::Error> {
+        let (phrase, options) = match entry {
+            RedAlertVoicePhraseEntry::Plain(phrase) => (phrase, Default::default()),
+            RedAlertVoicePhraseEntry::Detailed { phrase, options } => (phrase, options),
+        };
+        let phrase = SearchPhrase::try_from(phrase.clone())
+            .map_err(|error| format!("invalid phrase \"{}\": {}", phrase, error))?;
+        Ok(Self { phrase, options })
+    }
+}
+
+impl From<RedAlertVoicePhrase> for RedAlertVoicePhraseEntry {
+    fn from(voice_phrase: RedAlertVoicePhrase) -> Self {
+        if voice_phrase.options.is_empty() {
+            Self::Plain(voice_phrase.phrase.into())
+        } else {
+            Self::Detailed {
+                phrase: voice_phrase.phrase.into(),
+                options: voice_phrase.options,
+            }
+        }
+    }
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum RedAlertVoiceTarget<ID> {
+    User(ID),
+    Role(ID),
+}
+
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+#[serde(
+    from = "RedAlertVoiceAliasEntry<ID>",
+    into = "RedAlertVoiceAliasEntry<ID>"
+)]
+pub struct RedAlertVoiceAlias<ID: Clone + Eq + Hash> {
+    pub users_ids: HashSet<ID>,
+    pub roles_ids: HashSet<ID>,
+    pub options: RedAlertVoiceMatchOptions,
+}
+
+impl<ID: Clone + Eq + Hash> RedAlertVoiceAlias<ID> {
+    pub fn new(target: RedAlertVoiceTarget<ID>) -> Self {
+        let mut alias = Self {
+            users_ids: HashSet::new(),
+            roles_ids: HashSet::new(),
+            options: Default::default(),
+        };
+        alias.switch_target(target);
+        alias
+    }
+    pub fn is_empty(&self) -> bool {
+        self.users_ids.is_empty() && self.roles_ids.is_empty()
+    }
+    /// Adds the target to the alias, or removes it if it is already there.
+    /// Returns `true` if the target was added.
+    pub fn switch_target(&mut self, target: RedAlertVoiceTarget<ID>) -> bool {
+        let ids = match &target {
+            RedAlertVoiceTarget::User(_) => &mut self.users_ids,
+            RedAlertVoiceTarget::Role(_) => &mut self.roles_ids,
+        };
+        let (RedAlertVoiceTarget::User(id) | RedAlertVoiceTarget::Role(id)) = target;
+        if ids.remove(&id) {
+            false
+        } else {
+            ids.insert(id);
+            true
+        }
+    }
+    pub fn targets(&self) -> impl Iterator<Item = RedAlertVoiceTarget<&ID>> {
+        self.users_ids
+            .iter()
+            .map(RedAlertVoiceTarget::User)
+            .chain(self.roles_ids.iter().map(RedAlertVoiceTarget::Role))
+    }
+}
+
+#[derive(Serialize, Deserialize)]
+#[serde(untagged)]
+pub enum RedAlertVoiceAliasEntry<ID: Eq + Hash> {
+    Plain(ID),
+    Detailed {
+        #[serde(default = "HashSet::new", skip_serializing_if = "HashSet::is_empty")]
+        users_ids: HashSet<ID>,
+        #[serde(default = "HashSet::new", skip_serializing_if = "HashSet::is_empty")]
+        roles_ids: HashSet<ID>,
+        #[serde(flatten)]
+        options: RedAlertVoiceMatchOptions,
+    },
+}
+
+impl<ID: Clone + Eq + Hash> From<RedAlertVoiceAliasEntry<ID>> for RedAlertVoiceAlias<ID> {
+    fn from(entry: RedAlertVoiceAliasEntry<ID>) -> Self {
+        match entry {
+            RedAlertVoiceAliasEntry::Plain(user_id) => {
+                Self::new(RedAlertVoiceTarget::User(user_id))
+            }
+            RedAlertVoiceAliasEntry::Detailed {
+                users_ids,
+                roles_ids,
+                options,
+            } => Self {
+                users_ids,
+                roles_ids,
+                options,
+            },
+        }
+    }
+}
+
+impl<ID: Clone + Eq + Hash> From<RedAlertVoiceAlias<ID>> for RedAlertVoiceAliasEntry<ID> {
+    fn from(alias: RedAlertVoiceAlias<ID>) -> Self {
+        if alias.options.is_empty() && alias.roles_ids.is_empty() && alias.users_ids.len() == 1 {
+            if let Some(user_id) = alias.users_ids.iter().next() {
+                return Self::Plain(user_id.clone());
+            }
+        }
+        Self::Detailed {
+            users_ids: alias.users_ids,
+            roles_ids: alias.roles_ids,
+            options: alias.options,
+        }
+    }
+}
+
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+pub struct RedAlertVoiceImmunity<ID: Eq + Hash> {
+    #[serde(default = "HashSet::new")]
+    pub users_ids: HashSet<ID>,
+    #[serde(default = "HashSet::new")]
+    pub roles_ids: HashSet<ID>,
+}
+
+impl<ID: Eq + Hash> Default for RedAlertVoiceImmunity<ID> {
+    fn default() -> Self {
+        Self {
+            users_ids: HashSet::new(),
+            roles_ids: HashSet::new(),
+        }
+    }
+}
+
+impl<ID: Eq + Hash> RedAlertVoiceImmunity<ID> {
+    /// Adds the target to the immunity list, or removes it if it is already there.
+    /// Returns `true` if the target was added.
+    pub fn switch_target(&mut self, target: RedAlertVoiceTarget<ID>) -> bool {
+        let ids = match &target {
+            RedAlertVoiceTarget::User(_) => &mut self.users_ids,
+            RedAlertVoiceTarget::Role(_) => &mut self.roles_ids,
+        };
+        let (RedAlertVoiceTarget::User(id) | RedAlertVoiceTarget::Role(id)) = target;
+        if ids.remove(&id) {
+            false
+        } else {
+            ids.insert(id);
+            true
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn baseline_and_detailed_entries_are_deserialized() {
+        let voice_config: RedAlertVoiceConfig<u64> = serde_yaml::from_str(
+            r#"
+target_words:
+  - "красная тревога"
+  - "код красный"
+self_words:
+  - "запретное слово"
+aliases:
+  "алена": 111111111111111111
+similarity_threshold: 0.75
+editors: null
+"#,
+        )
+        .unwrap();
+        assert_eq!(voice_config.target_words[1].phrase.text(), "код красный");
+        assert!(voice_config.target_words[1].options.is_empty());
+        assert_eq!(
+            voice_config.aliases["алена"],
+            RedAlertVoiceAlias::new(RedAlertVoiceTarget::User(111111111111111111))
+        );
+        let voice_config: RedAlertVoiceConfig<u64> = serde_yaml::from_str(
+            r#"
+target_words:
+  - phrase: '/красн\w+ тревог\w+/'
+    similarity_threshold: 0.9
+self_words: []
+aliases:
+  "вадим":
+    users_ids: [222222222222222222]
+    roles_ids: [333333333333333333]
+    action:
+      type: timeout
+      minutes: 5
+similarity_threshold: 0.75
+editors: null
+"#,
+        )
+        .unwrap();
+        assert!(voice_config.target_words[0].phrase.is_pattern());
+        assert_eq!(
+            voice_config.target_words[0].options.similarity_threshold,
+            Some(0.9)
+        );
+        let alias = &voice_config.aliases["вадим"];
+        assert_eq!(alias.roles_ids, HashSet::from([333333333333333333]));
+        assert_eq!(
+            alias.options.action,
+            Some(RedAlertActionKind::Timeout { minutes: 5 })
+        );
+    }
+
+    #[test]
+    fn invalid_pattern_error_names_the_phrase() {
+        let error = serde_yaml::from_str::<RedAlertVoicePhrase>(r#"phrase: "/красн(/""#)
+            .err()
+            .unwrap();
+        assert!(error.to_string().contains("invalid phrase \"/красн(/\""));
+    }
+}
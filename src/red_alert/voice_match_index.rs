@@ -0,0 +1,355 @@
+use super::super::components::*;
+use super::*;
+use std::collections::HashMap;
+use std::hash::Hash;
+
+/// Guild voice config compiled for matching: configured phrases are normalized and their words
+/// are put into the n-gram index once, when the config changes, instead of on every
+/// recognition result.
+pub struct RedAlertVoiceMatchIndex<ID: Clone + Eq + Hash> {
+    config: RedAlertVoiceConfig<ID>,
+    aliases: Vec<(String, RedAlertVoiceAlias<ID>)>,
+    normalized_phrases: HashMap<String, String>,
+    ngram_words_index: NgramWordsIndex,
+}
+
+impl<ID: Clone + Eq + Hash> RedAlertVoiceMatchIndex<ID> {
+    pub fn new(
+        config: &RedAlertVoiceConfig<ID>,
+        derived_aliases: Option<&HashMap<String, RedAlertVoiceAlias<ID>>>,
+    ) -> Self {
+        let mut aliases: Vec<(String, RedAlertVoiceAlias<ID>)> = config
+            .aliases
+            .iter()
+            .map(|(name, alias)| (name.clone(), alias.clone()))
+            .collect();
+        for (name, alias) in derived_aliases.into_iter().flatten() {
+            if config.aliases.contains_key(name) {
+                continue;
+            }
+            aliases.push((name.clone(), alias.clone()));
+        }
+        let mut phrases: Vec<String> = vec![];
+        for voice_phrase in config.self_words.iter().chain(&config.target_words) {
+            if voice_phrase.phrase.is_pattern() {
+                continue;
+            }
+            phrases.push(voice_phrase.phrase.text().to_owned());
+        }
+        for (name, _) in &aliases {
+            phrases.push(name.clone());
+            for target_word in &config.target_words {
+                phrases.push(vec![target_word.phrase.text().to_owned(), name.clone()].join(SPACE));
+            }
+        }
+        phrases.extend(config.exceptions.iter().cloned());
+        let normalized_phrases: HashMap<String, String> = phrases
+            .into_iter()
+            .map(|phrase| {
+                let normalized_phrase = config.normalization.normalize_phrase(&phrase);
+                (phrase, normalized_phrase)
+            })
+            .collect();
+        let ngram_words_index = NgramWordsIndex::new(
+            normalized_phrases
+                .values()
+                .flat_map(|normalized_phrase| normalized_phrase.split_whitespace()),
+        );
+        Self {
+            config: config.clone(),
+            aliases,
+            normalized_phrases,
+            ngram_words_index,
+        }
+    }
+
+    pub fn config(&self) -> &RedAlertVoiceConfig<ID> {
+        &self.config
+    }
+
+    pub fn should_kick<'a, 'm: 'a>(
+        &'m self,
+        author_user_id: &'a ID,
+        text: &String,
+    ) -> HashMap<RedAlertVoiceTarget<&'a ID>, RedAlertVoiceSearchResult> {
+        Self::kicks_targets(self.rules_reports(author_user_id, text, 0.0))
+    }
+
+    /// Collects matched rules into the targets to kick, only the first matched self word is used.
+    pub fn kicks_targets<'a>(
+        rules_reports: Vec<RedAlertVoiceRuleReport<&'a ID>>,
+    ) -> HashMap<RedAlertVoiceTarget<&'a ID>, RedAlertVoiceSearchResult> {
+        let mut targets = HashMap::new();
+        let mut is_self_word_found = false;
+        for rule_report in rules_reports {
+            let Some(search_result) = rule_report.search_result else {
+                continue;
+            };
+            if rule_report.is_self_word {
+                if is_self_word_found {
+                    continue;
+                }
+                is_self_word_found = true;
+            }
+            for target in rule_report.targets {
+                targets.insert(target, search_result.clone());
+            }
+        }
+        targets
+    }
+
+    /// Reports every rule that matched the text, or missed the match while the phrase and each
+    /// of its words are no further than `near_miss_margin` from the similarity threshold.
+    pub fn rules_reports<'a, 'm: 'a>(
+        &'m self,
+        author_user_id: &'a ID,
+        text: &String,
+        near_miss_margin: f32,
+    ) -> Vec<RedAlertVoiceRuleReport<&'a ID>> {
+        let config = &self.config;
+        let normalized_text = config.normalization.normalize(text);
+        let text_words: Vec<&str> = normalized_text.words.iter().map(String::as_str).collect();
+        let session = RedAlertVoiceMatchSession {
+            index: self,
+            guild_options: RedAlertVoiceMatchOptions {
+                similarity_threshold: Some(config.similarity_threshold),
+                matcher: Some(config.matcher),
+                phonetic_weight: Some(config.phonetic_weight),
+                action: Some(config.action.clone()),
+                siren: config.siren.path.clone(),
+            },
+            normalized_text: &normalized_text,
+            ngram_matcher: self.ngram_words_index.matcher(&text_words),
+            text_words,
+        };
+        let is_reported = |phrase_report: &PhraseMatchReport| -> bool {
+            if phrase_report.phrase_match.is_some() {
+                return true;
+            }
+            let near_miss_threshold = phrase_report.similarity_threshold - near_miss_margin;
+            near_miss_margin > 0.0
+                && phrase_report.similarity >= near_miss_threshold
+                && phrase_report
+                    .words
+                    .iter()
+                    .all(|word_report| word_report.similarity >= near_miss_threshold)
+        };
+        let mut rules_reports: Vec<RedAlertVoiceRuleReport<&'a ID>> = vec![];
+        for self_word in &config.self_words {
+            let phrase_report = session.report_phrase(self_word);
+            if !is_reported(&phrase_report) {
+                continue;
+            }
+            rules_reports.push(RedAlertVoiceRuleReport {
+                word: self_word.phrase.text().to_owned(),
+                is_self_word: true,
+                targets: vec![RedAlertVoiceTarget::User(author_user_id)],
+                search_result: phrase_report.phrase_match.clone().map(|phrase_match| {
+                    RedAlertVoiceSearchResult::new(
+                        self_word.phrase.text().to_owned(),
+                        phrase_match,
+                        &self_word.options.or(&session.guild_options),
+                    )
+                }),
+                phrases_reports: vec![phrase_report],
+            });
+        }
+        for target_word in &config.target_words {
+            let target_word_report = session.report_phrase(target_word);
+            if !is_reported(&target_word_report) {
+                continue;
+            }
+            let is_nearby = config.proximity_window.is_some() || target_word.phrase.is_pattern();
+            for (name, alias) in &self.aliases {
+                let target_word_name =
+                    vec![target_word.phrase.text().to_owned(), name.to_owned()].join(SPACE);
+                let (phrase_match, phrases_reports) = if is_nearby {
+                    let name_report = session.report_text(name, &alias.options);
+                    if !is_reported(&name_report) {
+                        continue;
+                    }
+                    (
+                        session.nearby_match(
+                            &target_word_report,
+                            &name_report,
+                            config.proximity_window,
+                        ),
+                        vec![target_word_report.clone(), name_report],
+                    )
+                } else {
+                    let phrase_report = session
+                        .report_text(&target_word_name, &alias.options.or(&target_word.options));
+                    if !is_reported(&phrase_report) {
+                        continue;
+                    }
+                    (phrase_report.phrase_match.clone(), vec![phrase_report])
+                };
+                rules_reports.push(RedAlertVoiceRuleReport {
+                    search_result: phrase_match.map(|phrase_match| {
+                        RedAlertVoiceSearchResult::new(
+                            target_word_name.clone(),
+                            phrase_match,
+                            &alias
+                                .options
+                                .or(&target_word.options)
+                                .or(&session.guild_options),
+                        )
+                    }),
+                    word: target_word_name,
+                    is_self_word: false,
+                    targets: alias.targets().collect(),
+                    phrases_reports,
+                });
+            }
+            if target_word_report.phrase_match.is_none() {
+                rules_reports.push(RedAlertVoiceRuleReport {
+                    word: target_word.phrase.text().to_owned(),
+                    is_self_word: false,
+                    targets: vec![],
+                    search_result: None,
+                    phrases_reports: vec![target_word_report],
+                });
+            }
+        }
+        if rules_reports
+            .iter()
+            .any(|rule_report| rule_report.search_result.is_some())
+        {
+            let exceptions_matches: Vec<(&String, PhraseMatch)> = config
+                .exceptions
+                .iter()
+                .filter_map(|exception| {
+                    session
+                        .report_text(exception, &session.guild_options)
+                        .phrase_match
+                        .map(|phrase_match| (exception, phrase_match))
+                })
+                .collect();
+            for search_result in rules_reports
+                .iter_mut()
+                .filter_map(|rule_report| rule_report.search_result.as_mut())
+            {
+                search_result.suppressed_by = exceptions_matches
+                    .iter()
+                    .find(|(_, phrase_match)| {
+                        phrase_match.span.start < search_result.span.end
+                            && search_result.span.start < phrase_match.span.end
+                    })
+                    .map(|(exception, _)| (*exception).clone());
+            }
+        }
+        rules_reports
+    }
+}
+
+pub struct RedAlertVoiceRuleReport<ID> {
+    pub word: String,
+    pub is_self_word: bool,
+    pub targets: Vec<RedAlertVoiceTarget<ID>>,
+    pub phrases_reports: Vec<PhraseMatchReport>,
+    pub search_result: Option<RedAlertVoiceSearchResult>,
+}
+
+impl<ID> RedAlertVoiceRuleReport<ID> {
+    pub fn similarity(&self) -> f32 {
+        self.phrases_reports
+            .iter()
+            .map(|phrase_report| phrase_report.similarity)
+            .sum::<f32>()
+            / (self.phrases_reports.len().max(1) as f32)
+    }
+    pub fn similarity_threshold(&self) -> f32 {
+        self.phrases_reports
+            .iter()
+            .map(|phrase_report| phrase_report.similarity_threshold)
+            .sum::<f32>()
+            / (self.phrases_reports.len().max(1) as f32)
+    }
+}
+
+struct RedAlertVoiceMatchSession<'m, 't, ID: Clone + Eq + Hash> {
+    index: &'m RedAlertVoiceMatchIndex<ID>,
+    guild_options: RedAlertVoiceMatchOptions,
+    normalized_text: &'t NormalizedText,
+    text_words: Vec<&'t str>,
+    ngram_matcher: NgramIndexedPhraseMatcher<'m>,
+}
+
+impl<'m, 't, ID: Clone + Eq + Hash> RedAlertVoiceMatchSession<'m, 't, ID> {
+    fn to_source_match(&self, phrase_match: PhraseMatch) -> PhraseMatch {
+        PhraseMatch {
+            real_phrase: self.normalized_text.source_phrase(&phrase_match.span),
+            similarity: phrase_match.similarity,
+            span: self.normalized_text.source_span(&phrase_match.span),
+        }
+    }
+    fn report_text(&self, query: &str, options: &RedAlertVoiceMatchOptions) -> PhraseMatchReport {
+        let options = options.or(&self.guild_options);
+        let similarity_threshold = options
+            .similarity_threshold
+            .unwrap_or_default()
+            .min(1.0)
+            .max(0.0);
+        let matcher = PhoneticBlendPhraseMatcher {
+            base: match options.matcher.unwrap_or_default() {
+                PhraseMatcherKind::Ngram => &self.ngram_matcher,
+                matcher_kind => matcher_kind.matcher(),
+            },
+            phonetic_weight: options.phonetic_weight.unwrap_or_default(),
+        };
+        let query = match self.index.normalized_phrases.get(query) {
+            Some(normalized_query) => normalized_query.clone(),
+            None => self.index.config.normalization.normalize_phrase(query),
+        };
+        let mut report = matcher.explain(&self.text_words, &query, similarity_threshold);
+        report.phrase_match = report
+            .phrase_match
+            .map(|phrase_match| self.to_source_match(phrase_match));
+        report
+    }
+    fn report_phrase(&self, voice_phrase: &RedAlertVoicePhrase) -> PhraseMatchReport {
+        if !voice_phrase.phrase.is_pattern() {
+            return self.report_text(voice_phrase.phrase.text(), &voice_phrase.options);
+        }
+        let phrase_match = voice_phrase
+            .phrase
+            .search_pattern(&self.text_words)
+            .map(|phrase_match| self.to_source_match(phrase_match));
+        PhraseMatchReport {
+            words: vec![],
+            similarity: if phrase_match.is_some() { 1.0 } else { 0.0 },
+            similarity_threshold: 1.0,
+            phrase_match,
+        }
+    }
+    fn nearby_match(
+        &self,
+        first_report: &PhraseMatchReport,
+        second_report: &PhraseMatchReport,
+        window: Option<usize>,
+    ) -> Option<PhraseMatch> {
+        let first_match = first_report.phrase_match.as_ref()?;
+        let second_match = second_report.phrase_match.as_ref()?;
+        let start = first_match.span.start.min(second_match.span.start);
+        let end = first_match.span.end.max(second_match.span.end);
+        let is_overlapped = first_match.span.start < second_match.span.end
+            && second_match.span.start < first_match.span.end;
+        let is_out_of_window = if let Some(window) = window {
+            end - start > window
+        } else {
+            second_match.span.start != first_match.span.end
+        };
+        if is_overlapped || is_out_of_window {
+            return None;
+        }
+        let first_len = first_match.span.len() as f32;
+        let second_len = second_match.span.len() as f32;
+        Some(PhraseMatch {
+            real_phrase: self.normalized_text.source_words[start..end].join(SPACE),
+            similarity: (first_match.similarity * first_len
+                + second_match.similarity * second_len)
+                / (first_len + second_len),
+            span: start..end,
+        })
+    }
+}
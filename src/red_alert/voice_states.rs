@@ -0,0 +1,47 @@
+use serenity::model::id::GuildId;
+use serenity::model::prelude::{ChannelId, UserId, VoiceState};
+use std::collections::HashMap;
+
+/// Voice channels of users tracked from gateway events, used when the cache misses them.
+#[derive(Default)]
+pub struct RedAlertVoiceStates(HashMap<GuildId, HashMap<UserId, ChannelId>>);
+
+impl RedAlertVoiceStates {
+    pub fn update(&mut self, voice_state: &VoiceState) {
+        let Some(guild_id) = voice_state.guild_id else {
+            return;
+        };
+        match voice_state.channel_id {
+            Some(channel_id) => {
+                self.0
+                    .entry(guild_id)
+                    .or_default()
+                    .insert(voice_state.user_id, channel_id);
+            }
+            None => {
+                let Some(guild_voice_states) = self.0.get_mut(&guild_id) else {
+                    return;
+                };
+                guild_voice_states.remove(&voice_state.user_id);
+                if guild_voice_states.is_empty() {
+                    self.0.remove(&guild_id);
+                }
+            }
+        }
+    }
+    /// Replaces the tracked guild with the voice states received with the guild.
+    pub fn seed(&mut self, guild_id: GuildId, voice_states: &HashMap<UserId, VoiceState>) {
+        let guild_voice_states: HashMap<UserId, ChannelId> = voice_states
+            .iter()
+            .filter_map(|(user_id, voice_state)| Some((*user_id, voice_state.channel_id?)))
+            .collect();
+        if guild_voice_states.is_empty() {
+            self.0.remove(&guild_id);
+        } else {
+            self.0.insert(guild_id, guild_voice_states);
+        }
+    }
+    pub fn channel_id(&self, guild_id: &GuildId, user_id: &UserId) -> Option<ChannelId> {
+        self.0.get(guild_id)?.get(user_id).copied()
+    }
+}
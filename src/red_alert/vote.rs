@@ -0,0 +1,139 @@
+use super::super::components::*;
+use super::*;
+use serde::{Deserialize, Serialize};
+use serenity::futures::StreamExt;
+use serenity::model::id::GuildId;
+use serenity::model::prelude::{ChannelId, ReactionType, UserId};
+use serenity::prelude::{Context, Mentionable};
+use std::collections::HashSet;
+use std::time::Duration;
+
+const VOTE_EMOJI: char = '✅';
+
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+pub struct RedAlertVoteConfig {
+    pub channel_id: u64,
+    pub required_votes: usize,
+    pub timeout_seconds: u64,
+}
+
+#[derive(Clone, Copy, Debug)]
+pub enum RedAlertVoteOutcome {
+    Confirmed(usize),
+    Expired(usize),
+    Failed,
+}
+
+impl RedAlertVoteOutcome {
+    pub fn is_confirmed(&self) -> bool {
+        match self {
+            RedAlertVoteOutcome::Confirmed(_) => true,
+            RedAlertVoteOutcome::Expired(_) | RedAlertVoteOutcome::Failed => false,
+        }
+    }
+    pub fn description(&self, l10n: &L10n) -> String {
+        match self {
+            RedAlertVoteOutcome::Confirmed(votes) => l10n.string(
+                "red-alert-vote-outcome-confirmed",
+                fluent_args![
+                    "votes" => *votes
+                ],
+            ),
+            RedAlertVoteOutcome::Expired(votes) => l10n.string(
+                "red-alert-vote-outcome-expired",
+                fluent_args![
+                    "votes" => *votes
+                ],
+            ),
+            RedAlertVoteOutcome::Failed => {
+                l10n.string("red-alert-vote-outcome-failed", fluent_args![])
+            }
+        }
+    }
+}
+
+/// Asks members of the target voice channel to confirm the punishment by reactions.
+pub async fn community_vote(
+    ctx: &Context,
+    l10n: &L10n,
+    guild_id: &GuildId,
+    target_id: &UserId,
+    full_text: &str,
+    reason: &RedAlertVoiceSearchResult,
+    vote_config: &RedAlertVoteConfig,
+) -> RedAlertVoteOutcome {
+    let voice_channel_id = |user_id: &UserId| -> Option<ChannelId> {
+        ctx.cache
+            .guild(guild_id)?
+            .voice_states
+            .get(user_id)?
+            .channel_id
+    };
+    let Some(target_channel_id) = voice_channel_id(target_id) else {
+        return RedAlertVoteOutcome::Failed;
+    };
+    let Ok(mut message) = ChannelId(vote_config.channel_id)
+        .say(
+            ctx,
+            l10n.string(
+                "red-alert-vote-message",
+                fluent_args![
+                    "target-name" => target_id.mention().to_string(),
+                    "reason-text" => full_text,
+                    "action" => reason.action.description(l10n),
+                    "channel-name" => target_channel_id.mention().to_string(),
+                    "votes" => vote_config.required_votes,
+                    "seconds" => vote_config.timeout_seconds,
+                    "emoji" => VOTE_EMOJI.to_string()
+                ],
+            ),
+        )
+        .await
+    else {
+        return RedAlertVoteOutcome::Failed;
+    };
+    if message.react(ctx, VOTE_EMOJI).await.is_err() {
+        return RedAlertVoteOutcome::Failed;
+    }
+    let bot_user_id = ctx.cache.current_user_id();
+    let mut voters: HashSet<UserId> = HashSet::new();
+    let mut reactions = message
+        .await_reactions(ctx)
+        .added(true)
+        .removed(false)
+        .timeout(Duration::from_secs(vote_config.timeout_seconds))
+        .build();
+    while let Some(reaction_action) = reactions.next().await {
+        let reaction = reaction_action.as_inner_ref();
+        if reaction.emoji != ReactionType::from(VOTE_EMOJI) {
+            continue;
+        }
+        let Some(voter_id) = reaction.user_id else {
+            continue;
+        };
+        if voter_id == bot_user_id || voter_id == *target_id {
+            continue;
+        }
+        if voice_channel_id(&voter_id) != Some(target_channel_id) {
+            continue;
+        }
+        voters.insert(voter_id);
+        if voters.len() >= vote_config.required_votes {
+            break;
+        }
+    }
+    let outcome = if voters.len() >= vote_config.required_votes {
+        RedAlertVoteOutcome::Confirmed(voters.len())
+    } else {
+        RedAlertVoteOutcome::Expired(voters.len())
+    };
+    let result_text = l10n.string(
+        "red-alert-vote-result",
+        fluent_args![
+            "target-name" => target_id.mention().to_string(),
+            "outcome" => outcome.description(l10n)
+        ],
+    );
+    let _ = message.edit(ctx, |m| m.content(result_text)).await;
+    outcome
+}